@@ -0,0 +1,175 @@
+use poly_eval::Polynomial;
+
+use crate::newton::newton_method;
+
+/// Deflates the function `f` by the roots that have already been found.
+///
+/// The deflated function is `g(x) = f(x) / ((x - r_0)(x - r_1)...(x - r_k))`, so the known roots
+/// are no longer roots of `g` and a solver started near one of them will move on to another root.
+///
+/// # Arguments
+///
+/// * `f` - The function to be deflated.
+/// * `roots` - The roots of `f` that have already been found.
+///
+/// # Returns
+///
+/// The deflated function `g`.
+pub fn deflate<'a>(f: impl Fn(f64) -> f64 + 'a, roots: &'a [f64]) -> impl Fn(f64) -> f64 + 'a {
+    move |x| f(x) / roots.iter().map(|r| x - r).product::<f64>()
+}
+
+/// Builds the derivative of the deflated function returned by [`deflate`].
+///
+/// The derivative is `g'(x) = (f'(x) - f(x) * sum(1 / (x - r_i))) / ((x - r_0)(x - r_1)...(x - r_k))`.
+///
+/// # Arguments
+///
+/// * `f` - The function to be deflated.
+/// * `f_prime` - The derivative of the function `f`.
+/// * `roots` - The roots of `f` that have already been found.
+///
+/// # Returns
+///
+/// The derivative of the deflated function.
+pub fn deflate_derivative<'a>(
+    f: impl Fn(f64) -> f64 + 'a,
+    f_prime: impl Fn(f64) -> f64 + 'a,
+    roots: &'a [f64],
+) -> impl Fn(f64) -> f64 + 'a {
+    move |x| {
+        let product = roots.iter().map(|r| x - r).product::<f64>();
+        let sum = roots.iter().map(|r| 1.0 / (x - r)).sum::<f64>();
+        (f_prime(x) - f(x) * sum) / product
+    }
+}
+
+/// Finds successive roots of a function by applying a solver to the implicitly deflated function.
+///
+/// After each root is found, the function is deflated by it (see [`deflate`]) and the solver is run
+/// again, so the same root is not found twice.
+///
+/// # Arguments
+///
+/// * `f` - The function for which the roots need to be found.
+/// * `f_prime` - The derivative of the function `f`.
+/// * `count` - The maximum number of roots to find.
+/// * `solver` - The solver to be used. It receives the deflated function and its derivative and
+///   returns the root found, or `None` if it does not converge.
+///
+/// # Returns
+///
+/// The roots found, in the order they were found. Stops early when the solver fails.
+pub fn deflation_roots<S>(
+    f: impl Fn(f64) -> f64,
+    f_prime: impl Fn(f64) -> f64,
+    count: usize,
+    solver: S,
+) -> Vec<f64>
+where
+    S: Fn(&dyn Fn(f64) -> f64, &dyn Fn(f64) -> f64) -> Option<f64>,
+{
+    let mut roots: Vec<f64> = vec![];
+    while roots.len() < count {
+        let root = {
+            let g = deflate(&f, &roots);
+            let g_prime = deflate_derivative(&f, &f_prime, &roots);
+            solver(&g, &g_prime)
+        };
+        match root {
+            Some(root) => roots.push(root),
+            None => break,
+        }
+    }
+    roots
+}
+
+/// Finds successive roots of a function using the Newton's method with implicit deflation.
+///
+/// Every root found on the deflated function is polished by the Newton's method on the original
+/// function `f`, so the accuracy of later roots does not suffer from the deflation.
+///
+/// # Arguments
+///
+/// * `f` - The function for which the roots need to be found.
+/// * `f_prime` - The derivative of the function `f`.
+/// * `count` - The maximum number of roots to find.
+/// * `initial_guess` - The initial guess used for every root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps for each root.
+///
+/// # Returns
+///
+/// The roots found, in the order they were found.
+pub fn deflated_newton_method(
+    f: impl Fn(f64) -> f64,
+    f_prime: impl Fn(f64) -> f64,
+    count: usize,
+    initial_guess: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> Vec<f64> {
+    deflation_roots(&f, &f_prime, count, |g, g_prime| {
+        let root = newton_method(g, g_prime, initial_guess, tolerance, max_steps)?;
+        Some(newton_method(&f, &f_prime, root, tolerance, max_steps).unwrap_or(root))
+    })
+}
+
+/// Finds the real roots of a polynomial using the Newton's method and synthetic division.
+///
+/// Each root is found on the deflated polynomial, polished on the original polynomial and then
+/// divided out by [`Polynomial::synthetic_division`]. The search stops when the Newton's method
+/// does not converge, e.g. when only complex roots are left.
+///
+/// # Arguments
+///
+/// * `c` - The coefficients of the polynomial, lowest degree first.
+/// * `initial_guess` - The initial guess used for every root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps for each root.
+///
+/// # Returns
+///
+/// The real roots found, in the order they were found.
+pub fn polynomial_deflation_roots(
+    c: &[f64],
+    initial_guess: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> Vec<f64> {
+    let mut roots = vec![];
+    let mut deflated = Polynomial::new(c);
+    while deflated.degree() > 0 {
+        let Some(root) = newton_method(
+            |x| horner(deflated.coefficients(), x).0,
+            |x| horner(deflated.coefficients(), x).1,
+            initial_guess,
+            tolerance,
+            max_steps,
+        ) else {
+            break;
+        };
+        let root = newton_method(
+            |x| horner(c, x).0,
+            |x| horner(c, x).1,
+            root,
+            tolerance,
+            max_steps,
+        )
+        .unwrap_or(root);
+        deflated = deflated.synthetic_division(root).0;
+        roots.push(root);
+    }
+    roots
+}
+
+/// Evaluates a polynomial and its derivative at `x` with the Horner's method.
+fn horner(c: &[f64], x: f64) -> (f64, f64) {
+    let mut p = 0.0;
+    let mut dp = 0.0;
+    for c_i in c.iter().rev() {
+        dp = dp * x + p;
+        p = p * x + c_i;
+    }
+    (p, dp)
+}
@@ -0,0 +1,80 @@
+use crate::bisect::bisect;
+use crate::deflation::{
+    deflate, deflated_newton_method, deflation_roots, polynomial_deflation_roots,
+};
+use crate::newton::newton_method;
+
+#[test]
+fn deflate_test() {
+    // f(x) = (x - 1)(x - 2)(x - 3), deflated by 1 and 2 leaves x - 3
+    let f = |x: f64| (x - 1.0) * (x - 2.0) * (x - 3.0);
+    let roots = vec![1.0, 2.0];
+    let g = deflate(f, &roots);
+    assert!((g(5.0) - 2.0).abs() < 1e-12);
+    assert!((g(0.0) + 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn deflated_newton_method_test() {
+    let f = |x: f64| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0;
+    let f_prime = |x: f64| 3.0 * x.powi(2) - 12.0 * x + 11.0;
+    let tol = 1e-10;
+
+    let mut roots = deflated_newton_method(f, f_prime, 3, 0.0, tol, 100);
+    assert_eq!(roots.len(), 3);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((roots[0] - 1.0).abs() < tol);
+    assert!((roots[1] - 2.0).abs() < tol);
+    assert!((roots[2] - 3.0).abs() < tol);
+}
+
+#[test]
+fn deflation_roots_with_newton_test() {
+    // f(x) = sin(x) has roots 0, pi, -pi, ... near 0.5
+    let f = |x: f64| x.sin();
+    let f_prime = |x: f64| x.cos();
+    let tol = 1e-10;
+
+    let roots = deflation_roots(f, f_prime, 3, |g, g_prime| {
+        newton_method(g, g_prime, 0.5, tol, 100)
+    });
+    assert_eq!(roots.len(), 3);
+    assert!(roots[0].abs() < tol);
+    for root in &roots[1..] {
+        assert!(root.sin().abs() < 1e-8);
+        assert!(root.abs() > 1.0);
+    }
+    assert!((roots[1] - roots[2]).abs() > 1.0);
+}
+
+#[test]
+fn deflation_roots_stops_when_solver_fails_test() {
+    // f(x) = x^2 - 4 has only two roots in [-3, 3]
+    let f = |x: f64| x.powi(2) - 4.0;
+    let f_prime = |x: f64| 2.0 * x;
+    let roots = deflation_roots(f, f_prime, 5, |g, _| bisect(g, -3.0, 0.5, 1e-8));
+    assert_eq!(roots.len(), 1);
+    assert!((roots[0] + 2.0).abs() < 1e-7);
+}
+
+#[test]
+fn polynomial_deflation_roots_test() {
+    // (x + 1)(x - 2)(x - 3)(x - 4) = x^4 - 8x^3 + 17x^2 + 2x - 24
+    let c = vec![-24.0, 2.0, 17.0, -8.0, 1.0];
+    let tol = 1e-10;
+    let mut roots = polynomial_deflation_roots(&c, 0.0, tol, 100);
+    assert_eq!(roots.len(), 4);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (root, expected) in roots.iter().zip([-1.0, 2.0, 3.0, 4.0]) {
+        assert!((root - expected).abs() < tol);
+    }
+}
+
+#[test]
+fn polynomial_deflation_roots_complex_left_test() {
+    // (x - 1)(x^2 + 1) = x^3 - x^2 + x - 1, only one real root
+    let c = vec![-1.0, 1.0, -1.0, 1.0];
+    let roots = polynomial_deflation_roots(&c, 0.5, 1e-10, 50);
+    assert_eq!(roots.len(), 1);
+    assert!((roots[0] - 1.0).abs() < 1e-10);
+}
@@ -0,0 +1,119 @@
+use poly_eval::poly_eval;
+
+/// Computes the inverse quadratic interpolation of three points.
+///
+/// Fits `x` as a quadratic polynomial in `y` through the points `(x_i, y_i)` and evaluates it at
+/// `y = 0`. The `y` values must be distinct.
+///
+/// # Arguments
+///
+/// * `x` - The three arguments.
+/// * `y` - The function values at `x`.
+///
+/// # Returns
+///
+/// The estimated root.
+pub fn inverse_quadratic_interpolation(x: [f64; 3], y: [f64; 3]) -> f64 {
+    x[0] * y[1] * y[2] / ((y[0] - y[1]) * (y[0] - y[2]))
+        + x[1] * y[0] * y[2] / ((y[1] - y[0]) * (y[1] - y[2]))
+        + x[2] * y[0] * y[1] / ((y[2] - y[0]) * (y[2] - y[1]))
+}
+
+/// Implements the inverse quadratic interpolation method for finding the root of a function.
+///
+/// Each step replaces the oldest of the three latest iterates with the inverse quadratic
+/// interpolation of them (see [`inverse_quadratic_interpolation`]).
+///
+/// # Arguments
+///
+/// * `f` - The function for which the root needs to be found.
+/// * `x0`, `x1`, `x2` - The three initial guesses for the root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The approximate root if convergence is achieved within the specified tolerance.
+/// * `None` - If two function values coincide or convergence is not achieved within `max_steps`.
+pub fn iqi_method(
+    f: impl Fn(f64) -> f64,
+    x0: f64,
+    x1: f64,
+    x2: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> Option<f64> {
+    let mut x = [x0, x1, x2];
+    let mut y = [f(x0), f(x1), f(x2)];
+    for _ in 0..max_steps {
+        let x_new = inverse_quadratic_interpolation(x, y);
+        if !x_new.is_finite() {
+            return None;
+        }
+        if (x_new - x[2]).abs() < tolerance {
+            return Some(x_new);
+        }
+        x = [x[1], x[2], x_new];
+        y = [y[1], y[2], f(x_new)];
+    }
+    None
+}
+
+/// Implements the root finding by inverse interpolation through the latest iterates.
+///
+/// Fits `x` as a polynomial in `y` through the `k` latest iterates, where `k` is the number of
+/// initial guesses, and evaluates it at `y = 0` to get the next iterate. The polynomial is built in
+/// Newton form from divided differences, with the function values as base points, and evaluated by
+/// [`poly_eval`]. Two initial guesses give the secant method and three give the inverse quadratic
+/// interpolation method.
+///
+/// # Arguments
+///
+/// * `f` - The function for which the root needs to be found.
+/// * `initial_guesses` - At least two distinct initial guesses for the root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps.
+///
+/// # Returns
+///
+/// * `Some(f64)` - The approximate root if convergence is achieved within the specified tolerance.
+/// * `None` - If two function values coincide or convergence is not achieved within `max_steps`.
+pub fn inverse_interpolation_method(
+    f: impl Fn(f64) -> f64,
+    initial_guesses: &[f64],
+    tolerance: f64,
+    max_steps: usize,
+) -> Option<f64> {
+    if initial_guesses.len() < 2 {
+        return None;
+    }
+    let mut x = initial_guesses.to_vec();
+    let mut y: Vec<f64> = x.iter().map(|&x_i| f(x_i)).collect();
+    for _ in 0..max_steps {
+        let c = divided_differences(&y, &x);
+        let b = y[..y.len() - 1].to_vec();
+        let x_new = poly_eval(&c, &[0.0], Some(&b))[0];
+        if !x_new.is_finite() {
+            return None;
+        }
+        if (x_new - x[x.len() - 1]).abs() < tolerance {
+            return Some(x_new);
+        }
+        x.remove(0);
+        y.remove(0);
+        x.push(x_new);
+        y.push(f(x_new));
+    }
+    None
+}
+
+/// Computes the Newton-form coefficients of the polynomial interpolating `(t_i, v_i)`.
+fn divided_differences(t: &[f64], v: &[f64]) -> Vec<f64> {
+    let mut c = v.to_vec();
+    for j in 1..c.len() {
+        for i in (j..c.len()).rev() {
+            c[i] = (c[i] - c[i - 1]) / (t[i] - t[i - j]);
+        }
+    }
+    c
+}
@@ -0,0 +1,59 @@
+use crate::inverse_interpolation::{
+    inverse_interpolation_method, inverse_quadratic_interpolation, iqi_method,
+};
+
+#[test]
+fn inverse_quadratic_interpolation_test() {
+    // x = 1 + y + y^2 is reproduced exactly, so the estimate is x(0) = 1
+    let y = [-1.0, 1.0, 2.0];
+    let x = y.map(|y: f64| 1.0 + y + y * y);
+    assert!((inverse_quadratic_interpolation(x, y) - 1.0).abs() < 1e-14);
+}
+
+#[test]
+fn iqi_method_test() {
+    let f = |x: f64| x.powi(3) + x - 1.0;
+    let root = iqi_method(f, 0.0, 1.0, 0.5, 1e-12, 50);
+    assert!(root.is_some());
+    assert!((root.unwrap() - 0.6823278038280193).abs() < 1e-12);
+}
+
+#[test]
+fn iqi_method_is_none_test() {
+    // f(x) = x^2 - 4 has equal values at -1 and 1
+    let f = |x: f64| x.powi(2) - 4.0;
+    assert!(iqi_method(f, -1.0, 1.0, 0.5, 1e-12, 50).is_none());
+}
+
+#[test]
+fn inverse_interpolation_method_test() {
+    let f = |x: f64| x.cos() - x;
+    let expected = 0.7390851332151607;
+    // secant, inverse quadratic and inverse cubic interpolation
+    for guesses in [
+        vec![0.0, 1.0],
+        vec![0.0, 1.0, 0.5],
+        vec![0.0, 1.0, 0.5, 0.8],
+    ] {
+        let root = inverse_interpolation_method(f, &guesses, 1e-12, 50);
+        assert!(root.is_some());
+        assert!((root.unwrap() - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn inverse_interpolation_method_matches_iqi_test() {
+    let f = |x: f64| x.exp() - 3.0;
+    let iqi = iqi_method(f, 0.0, 2.0, 1.0, 1e-13, 50).unwrap();
+    let general = inverse_interpolation_method(f, &[0.0, 2.0, 1.0], 1e-13, 50).unwrap();
+    assert!((iqi - 3.0_f64.ln()).abs() < 1e-13);
+    assert!((general - iqi).abs() < 1e-13);
+}
+
+#[test]
+fn inverse_interpolation_method_is_none_test() {
+    let f = |x: f64| x - 1.0;
+    assert!(inverse_interpolation_method(f, &[0.0], 1e-12, 50).is_none());
+    let f = |x: f64| x.powi(2) + 1.0;
+    assert!(inverse_interpolation_method(f, &[-1.0, 1.0, 2.0], 1e-12, 50).is_none());
+}
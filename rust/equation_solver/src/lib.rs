@@ -1,11 +1,26 @@
 #[allow(dead_code)]
 pub mod bisect;
+pub mod deflation;
 pub mod fpi;
+pub mod inverse_interpolation;
+pub mod multistart;
 pub mod newton;
+pub mod numerical_newton;
+pub mod vector_fpi;
 
 #[cfg(test)]
 mod bisect_tests;
 #[cfg(test)]
+mod deflation_tests;
+#[cfg(test)]
 mod fpi_tests;
 #[cfg(test)]
+mod inverse_interpolation_tests;
+#[cfg(test)]
+mod multistart_tests;
+#[cfg(test)]
 mod newton_test;
+#[cfg(test)]
+mod numerical_newton_tests;
+#[cfg(test)]
+mod vector_fpi_tests;
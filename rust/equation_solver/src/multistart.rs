@@ -0,0 +1,169 @@
+use std::thread;
+
+/// A distinct root found by [`multi_start`] together with the statistics of its basin of attraction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootBasin {
+    /// The root, averaged over all starts that converged to it.
+    pub root: f64,
+    /// The number of starts that converged to the root.
+    pub count: usize,
+    /// The fraction of all starts that converged to the root.
+    pub fraction: f64,
+    /// The smallest initial guess that converged to the root.
+    pub min_start: f64,
+    /// The largest initial guess that converged to the root.
+    pub max_start: f64,
+}
+
+/// The result of [`multi_start`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiStartResult {
+    /// The distinct roots found, in ascending order.
+    pub roots: Vec<RootBasin>,
+    /// The number of starts for which the solver did not converge.
+    pub failed: usize,
+    /// The total number of starts.
+    pub total: usize,
+}
+
+/// Generates `n` evenly spaced initial guesses in the interval `[a, b]`.
+///
+/// # Arguments
+///
+/// * `a` - The lower bound of the interval.
+/// * `b` - The upper bound of the interval.
+/// * `n` - The number of initial guesses.
+///
+/// # Returns
+///
+/// The initial guesses, including both ends of the interval when `n >= 2`.
+pub fn grid_starts(a: f64, b: f64, n: usize) -> Vec<f64> {
+    match n {
+        0 => vec![],
+        1 => vec![(a + b) / 2.0],
+        _ => (0..n)
+            .map(|i| a + (b - a) * i as f64 / (n - 1) as f64)
+            .collect(),
+    }
+}
+
+/// Generates `n` uniformly distributed random initial guesses in the interval `[a, b)`.
+///
+/// The same `seed` always produces the same initial guesses.
+///
+/// # Arguments
+///
+/// * `a` - The lower bound of the interval.
+/// * `b` - The upper bound of the interval.
+/// * `n` - The number of initial guesses.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Returns
+///
+/// The initial guesses.
+pub fn random_starts(a: f64, b: f64, n: usize, seed: u64) -> Vec<f64> {
+    // splitmix64
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            let u = (z >> 11) as f64 / (1u64 << 53) as f64;
+            a + (b - a) * u
+        })
+        .collect()
+}
+
+/// Runs a scalar solver from many initial guesses in parallel and collects the distinct roots.
+///
+/// The initial guesses are split evenly across `threads` threads. Converged roots that lie within
+/// `tolerance` of each other are treated as the same root.
+///
+/// # Arguments
+///
+/// * `solver` - The solver to be used. It receives an initial guess and returns the root found, or
+///   `None` if it does not converge, e.g. `|x0| newton_method(f, f_prime, x0, 1e-10, 100)`.
+/// * `starts` - The initial guesses, see [`grid_starts`] and [`random_starts`].
+/// * `tolerance` - The distance below which two roots are considered the same.
+/// * `threads` - The number of threads to use. `0` uses the available parallelism of the machine.
+///
+/// # Returns
+///
+/// The distinct roots with their basin statistics.
+pub fn multi_start(
+    solver: impl Fn(f64) -> Option<f64> + Sync,
+    starts: &[f64],
+    tolerance: f64,
+    threads: usize,
+) -> MultiStartResult {
+    let threads = if threads == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        threads
+    };
+    let chunk_size = starts.len().div_ceil(threads).max(1);
+
+    let solver = &solver;
+    let results: Vec<(f64, Option<f64>)> = thread::scope(|scope| {
+        let handles: Vec<_> = starts
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&x0| (x0, solver(x0).filter(|root| root.is_finite())))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let mut converged: Vec<(f64, f64)> = results
+        .iter()
+        .filter_map(|&(x0, root)| root.map(|root| (root, x0)))
+        .collect();
+    converged.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let total = starts.len();
+    let mut roots: Vec<RootBasin> = vec![];
+    let mut first = f64::NAN;
+    let mut sum = 0.0;
+    for (root, x0) in converged.iter().copied() {
+        match roots.last_mut() {
+            Some(basin) if root - first <= tolerance => {
+                sum += root;
+                basin.count += 1;
+                basin.root = sum / basin.count as f64;
+                basin.min_start = basin.min_start.min(x0);
+                basin.max_start = basin.max_start.max(x0);
+            }
+            _ => {
+                first = root;
+                sum = root;
+                roots.push(RootBasin {
+                    root,
+                    count: 1,
+                    fraction: 0.0,
+                    min_start: x0,
+                    max_start: x0,
+                });
+            }
+        }
+    }
+    roots
+        .iter_mut()
+        .for_each(|basin| basin.fraction = basin.count as f64 / total as f64);
+
+    MultiStartResult {
+        roots,
+        failed: total - converged.len(),
+        total,
+    }
+}
@@ -0,0 +1,88 @@
+use crate::fpi::fixed_point_iter;
+use crate::multistart::{grid_starts, multi_start, random_starts};
+use crate::newton::newton_method;
+
+#[test]
+fn grid_starts_test() {
+    assert_eq!(grid_starts(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(grid_starts(0.0, 1.0, 1), vec![0.5]);
+    assert!(grid_starts(0.0, 1.0, 0).is_empty());
+}
+
+#[test]
+fn random_starts_test() {
+    let starts = random_starts(-2.0, 3.0, 1000, 42);
+    assert_eq!(starts.len(), 1000);
+    assert!(starts.iter().all(|x| (-2.0..3.0).contains(x)));
+    assert_eq!(starts, random_starts(-2.0, 3.0, 1000, 42));
+    assert_ne!(starts, random_starts(-2.0, 3.0, 1000, 43));
+}
+
+#[test]
+fn multi_start_newton_test() {
+    // f(x) = (x + 2)(x - 1)(x - 3) = x^3 - 2x^2 - 5x + 6
+    fn f(x: f64) -> f64 {
+        x.powi(3) - 2.0 * x.powi(2) - 5.0 * x + 6.0
+    }
+    fn f_prime(x: f64) -> f64 {
+        3.0 * x.powi(2) - 4.0 * x - 5.0
+    }
+
+    let starts = grid_starts(-5.0, 5.0, 101);
+    let result = multi_start(
+        |x0| newton_method(f, f_prime, x0, 1e-12, 100),
+        &starts,
+        1e-8,
+        4,
+    );
+    assert_eq!(result.total, 101);
+    assert_eq!(result.roots.len(), 3);
+    for (basin, expected) in result.roots.iter().zip([-2.0, 1.0, 3.0]) {
+        assert!((basin.root - expected).abs() < 1e-10);
+        assert!(basin.min_start <= basin.max_start);
+    }
+    let converged: usize = result.roots.iter().map(|basin| basin.count).sum();
+    assert_eq!(converged + result.failed, result.total);
+    let fraction: f64 = result.roots.iter().map(|basin| basin.fraction).sum();
+    assert!((fraction - converged as f64 / 101.0).abs() < 1e-12);
+    // the starts near each root belong to its basin
+    assert!(result.roots[0].min_start <= -3.0);
+    assert!(result.roots[2].max_start >= 4.0);
+}
+
+#[test]
+fn multi_start_same_result_for_any_threads_test() {
+    let f = |x: f64| x.sin();
+    let f_prime = |x: f64| x.cos();
+    let starts = random_starts(-10.0, 10.0, 200, 7);
+    let solver = |x0| newton_method(f, f_prime, x0, 1e-12, 50);
+
+    let single = multi_start(solver, &starts, 1e-8, 1);
+    let multi = multi_start(solver, &starts, 1e-8, 0);
+    assert_eq!(single.roots.len(), multi.roots.len());
+    for (a, b) in single.roots.iter().zip(&multi.roots) {
+        assert_eq!(a.count, b.count);
+        assert!((a.root - b.root).abs() < 1e-12);
+        assert!(
+            (a.root / std::f64::consts::PI).fract().abs() < 1e-9
+                || (a.root / std::f64::consts::PI).fract().abs() > 1.0 - 1e-9
+        );
+    }
+}
+
+#[test]
+fn multi_start_with_failed_starts_test() {
+    // x = x^2 only converges to 0 for |x0| < 1 and diverges for |x0| > 1
+    let starts = grid_starts(-3.0, 3.0, 13);
+    let result = multi_start(
+        |x0| fixed_point_iter(|x| x * x, x0, 1e-10, 100),
+        &starts,
+        1e-6,
+        3,
+    );
+    assert_eq!(result.roots.len(), 2);
+    assert!(result.roots[0].root.abs() < 1e-6);
+    assert_eq!(result.roots[0].count, 3);
+    assert_eq!(result.roots[1].root, 1.0);
+    assert_eq!(result.failed, 8);
+}
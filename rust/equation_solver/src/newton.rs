@@ -11,8 +11,8 @@
 ///
 /// The estimated root of the function.
 pub fn newton_method(
-    f: fn(f64) -> f64,
-    f_prime: fn(f64) -> f64,
+    f: impl Fn(f64) -> f64,
+    f_prime: impl Fn(f64) -> f64,
     initial_guess: f64,
     tolerance: f64,
     max_steps: usize,
@@ -47,8 +47,8 @@ pub fn newton_method(
 ///
 /// The estimated root of the function.
 pub fn improved_newton_method(
-    f: fn(f64) -> f64,
-    f_prime: fn(f64) -> f64,
+    f: impl Fn(f64) -> f64,
+    f_prime: impl Fn(f64) -> f64,
     m: usize,
     initial_guess: f64,
     tolerance: f64,
@@ -0,0 +1,211 @@
+use poly_eval::Complex64;
+
+/// The finite-difference scheme used to approximate the derivative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivativeScheme {
+    /// `(f(x + h) - f(x)) / h`, truncation error `O(h)`, step `h ~ sqrt(eps)`.
+    Forward,
+    /// `(f(x + h) - f(x - h)) / 2h`, truncation error `O(h^2)`, step `h ~ cbrt(eps)`.
+    Central,
+}
+
+/// The result of the Newton's method with a numerical derivative, together with diagnostics
+/// about the last derivative approximation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NumericalNewtonResult {
+    /// The estimated root, or `None` if the method did not converge.
+    pub root: Option<f64>,
+    /// The number of Newton steps performed.
+    pub iterations: usize,
+    /// The step size `h` used for the last derivative.
+    pub step_size: f64,
+    /// The last derivative approximation.
+    pub derivative: f64,
+    /// The estimated absolute error of the last derivative approximation.
+    pub derivative_error: f64,
+}
+
+/// Implements the Newton's method with the derivative approximated by finite differences.
+///
+/// # Arguments
+///
+/// * `f` - The function for which the root needs to be found.
+/// * `scheme` - The finite-difference scheme used for the derivative.
+/// * `initial_guess` - The initial guess for the root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps.
+///
+/// # Returns
+///
+/// The estimated root of the function.
+pub fn numerical_newton_method(
+    f: impl Fn(f64) -> f64,
+    scheme: DerivativeScheme,
+    initial_guess: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> Option<f64> {
+    numerical_newton_method_with_diagnostics(f, scheme, initial_guess, tolerance, max_steps).root
+}
+
+/// Implements the Newton's method with the derivative approximated by finite differences, and
+/// reports the derivative error estimate.
+///
+/// The step size is chosen from the machine epsilon and the scale of the function, which balances
+/// the truncation error against the rounding error: `h = 2 sqrt(eps |f| / |f''|)` for the forward
+/// scheme and `h = cbrt(3 eps |f| / |f'''|)` for the central scheme. `|f|` is the largest magnitude
+/// of `f` near `x` and the higher derivative is estimated by a pilot difference. When the estimate
+/// is unusable (e.g. `f` is linear) the step falls back to `sqrt(eps) * max(|x|, 1)` or
+/// `cbrt(eps) * max(|x|, 1)`, and it never moves further than a factor of 100 from them. The
+/// derivative error is estimated by comparing the approximations with step `h` and `2h`
+/// (Richardson), plus the rounding error `eps * |f| / h`.
+///
+/// # Arguments
+///
+/// * `f` - The function for which the root needs to be found.
+/// * `scheme` - The finite-difference scheme used for the derivative.
+/// * `initial_guess` - The initial guess for the root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps.
+///
+/// # Returns
+///
+/// The estimated root and the diagnostics of the last derivative approximation.
+pub fn numerical_newton_method_with_diagnostics(
+    f: impl Fn(f64) -> f64,
+    scheme: DerivativeScheme,
+    initial_guess: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> NumericalNewtonResult {
+    let mut result = NumericalNewtonResult::default();
+    let mut x = initial_guess;
+    for step in 1..=max_steps {
+        let fx = f(x);
+        let (h, fpx, error) = finite_difference(&f, scheme, x, fx);
+        result.iterations = step;
+        result.step_size = h;
+        result.derivative = fpx;
+        result.derivative_error = error;
+        if fpx == 0.0 {
+            return result;
+        }
+        let delta_x = fx / fpx;
+        x -= delta_x;
+        if delta_x.abs() < tolerance {
+            result.root = Some(x);
+            return result;
+        }
+    }
+    result
+}
+
+/// Implements the Newton's method with the derivative computed by the complex-step method.
+///
+/// The derivative is `Im(f(x + ih)) / h`, which involves no subtraction and is therefore accurate
+/// to the machine precision with a tiny step `h = 1e-20 * max(|x|, 1)`. The function `f` must be
+/// analytic and implemented for complex arguments of [`Complex64`].
+///
+/// # Arguments
+///
+/// * `f` - The function for which the root needs to be found, evaluated at complex arguments.
+/// * `initial_guess` - The initial guess for the root.
+/// * `tolerance` - The tolerance value for convergence.
+/// * `max_steps` - The maximum number of steps.
+///
+/// # Returns
+///
+/// The estimated root and the diagnostics of the last derivative approximation.
+pub fn complex_step_newton_method(
+    f: impl Fn(Complex64) -> Complex64,
+    initial_guess: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> NumericalNewtonResult {
+    let mut result = NumericalNewtonResult::default();
+    let mut x = initial_guess;
+    for step in 1..=max_steps {
+        let h = 1e-20 * x.abs().max(1.0);
+        let fz = f(Complex64::new(x, h));
+        let fx = fz.re;
+        let fpx = fz.im / h;
+        result.iterations = step;
+        result.step_size = h;
+        result.derivative = fpx;
+        result.derivative_error = f64::EPSILON * fpx.abs();
+        if fpx == 0.0 {
+            return result;
+        }
+        let delta_x = fx / fpx;
+        x -= delta_x;
+        if delta_x.abs() < tolerance {
+            result.root = Some(x);
+            return result;
+        }
+    }
+    result
+}
+
+/// Approximates `f'(x)` and returns the step size, the derivative and its error estimate.
+fn finite_difference(
+    f: impl Fn(f64) -> f64,
+    scheme: DerivativeScheme,
+    x: f64,
+    fx: f64,
+) -> (f64, f64, f64) {
+    let scale = x.abs().max(1.0);
+    match scheme {
+        DerivativeScheme::Forward => {
+            // pilot second difference for |f''|
+            let h0 = exact_step(x, f64::EPSILON.powf(0.25) * scale);
+            let (p1, p2) = (f(x + h0), f(x + 2.0 * h0));
+            let curvature = (p2 - 2.0 * p1 + fx) / (h0 * h0);
+            let magnitude = fx.abs().max(p1.abs()).max(p2.abs());
+            let h_opt = 2.0 * (f64::EPSILON * magnitude / curvature.abs()).sqrt();
+            let h = exact_step(x, optimal_step(h_opt, f64::EPSILON.sqrt() * scale));
+
+            let f1 = f(x + h);
+            let f2 = f(x + 2.0 * h);
+            let d1 = (f1 - fx) / h;
+            let d2 = (f2 - fx) / (2.0 * h);
+            let rounding = 2.0 * f64::EPSILON * fx.abs().max(f1.abs()) / h;
+            (h, d1, (d2 - d1).abs() + rounding)
+        }
+        DerivativeScheme::Central => {
+            // pilot third difference for |f'''|
+            let h0 = exact_step(x, f64::EPSILON.powf(0.2) * scale);
+            let (p1, p_1) = (f(x + h0), f(x - h0));
+            let (p2, p_2) = (f(x + 2.0 * h0), f(x - 2.0 * h0));
+            let third = (p2 - 2.0 * p1 + 2.0 * p_1 - p_2) / (2.0 * h0 * h0 * h0);
+            let magnitude = [fx, p1, p_1, p2, p_2]
+                .iter()
+                .fold(0.0_f64, |m, v| m.max(v.abs()));
+            let h_opt = (3.0 * f64::EPSILON * magnitude / third.abs()).cbrt();
+            let h = exact_step(x, optimal_step(h_opt, f64::EPSILON.cbrt() * scale));
+
+            let f1 = f(x + h);
+            let f_1 = f(x - h);
+            let f2 = f(x + 2.0 * h);
+            let f_2 = f(x - 2.0 * h);
+            let d1 = (f1 - f_1) / (2.0 * h);
+            let d2 = (f2 - f_2) / (4.0 * h);
+            let rounding = f64::EPSILON * f1.abs().max(f_1.abs()) / h;
+            (h, d1, (d2 - d1).abs() / 3.0 + rounding)
+        }
+    }
+}
+
+/// Uses the step balancing the truncation and rounding errors when it is usable, otherwise
+/// `h_default`, and keeps it within a factor of 100 of `h_default`.
+fn optimal_step(h_opt: f64, h_default: f64) -> f64 {
+    if h_opt.is_finite() && h_opt > 0.0 {
+        h_opt.clamp(h_default / 100.0, h_default * 100.0)
+    } else {
+        h_default
+    }
+}
+
+/// Adjusts `h` so that `x + h` is exactly representable and `(x + h) - x == h`.
+fn exact_step(x: f64, h: f64) -> f64 {
+    (x + h) - x
+}
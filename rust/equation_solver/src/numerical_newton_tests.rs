@@ -0,0 +1,88 @@
+use crate::numerical_newton::{
+    complex_step_newton_method, numerical_newton_method, numerical_newton_method_with_diagnostics,
+    DerivativeScheme,
+};
+use poly_eval::Complex64;
+
+#[test]
+fn numerical_newton_method_forward_test() {
+    let f = |x: f64| x.powi(2) - 4.0;
+    let root = numerical_newton_method(f, DerivativeScheme::Forward, 1.5, 1e-10, 100);
+    assert!(root.is_some());
+    assert!((root.unwrap() - 2.0).abs() < 1e-10);
+}
+
+#[test]
+fn numerical_newton_method_central_test() {
+    let f = |x: f64| x.cos() - x;
+    let root = numerical_newton_method(f, DerivativeScheme::Central, 0.0, 1e-12, 100);
+    assert!(root.is_some());
+    assert!((root.unwrap() - 0.7390851332151607).abs() < 1e-12);
+}
+
+#[test]
+fn numerical_newton_method_is_none_test() {
+    // f'(0) = 0 for f(x) = x^2 + 1, and there is no real root
+    let f = |x: f64| x.powi(2) + 1.0;
+    let root = numerical_newton_method(f, DerivativeScheme::Central, 0.0, 1e-10, 100);
+    assert!(root.is_none());
+}
+
+#[test]
+fn numerical_newton_method_diagnostics_test() {
+    // f(x) = x^3 - 2x - 5, f'(x) = 3x^2 - 2
+    let f = |x: f64| x.powi(3) - 2.0 * x - 5.0;
+    let f_prime = |x: f64| 3.0 * x.powi(2) - 2.0;
+    let root = 2.0945514815423265;
+
+    let forward =
+        numerical_newton_method_with_diagnostics(f, DerivativeScheme::Forward, 2.0, 1e-12, 100);
+    let central =
+        numerical_newton_method_with_diagnostics(f, DerivativeScheme::Central, 2.0, 1e-12, 100);
+    for result in [forward, central] {
+        assert!((result.root.unwrap() - root).abs() < 1e-12);
+        assert!(result.iterations > 1);
+        assert!(result.step_size > 0.0);
+        // the estimate covers the real error of the derivative
+        let error = (result.derivative - f_prime(result.root.unwrap())).abs();
+        assert!(error <= result.derivative_error * 10.0 + 1e-12);
+    }
+    assert!(forward.step_size < central.step_size);
+    assert!(central.derivative_error < forward.derivative_error);
+    assert!(forward.derivative_error < 1e-5);
+    assert!(central.derivative_error < 1e-8);
+}
+
+#[test]
+fn numerical_newton_method_step_size_test() {
+    // e^(10x) - 10 is strongly curved, the forward step shrinks below sqrt(eps)
+    let f = |x: f64| (10.0 * x).exp() - 10.0;
+    let result =
+        numerical_newton_method_with_diagnostics(f, DerivativeScheme::Forward, 0.3, 1e-12, 100);
+    assert!((result.root.unwrap() - 10.0_f64.ln() / 10.0).abs() < 1e-12);
+    assert!(result.step_size < f64::EPSILON.sqrt() / 2.0);
+
+    // a linear function has no curvature, the step falls back to sqrt(eps) * max(|x|, 1)
+    let f = |x: f64| 3.0 * x - 1.0;
+    let result =
+        numerical_newton_method_with_diagnostics(f, DerivativeScheme::Forward, 0.0, 1e-12, 100);
+    assert!((result.step_size - f64::EPSILON.sqrt()).abs() < 1e-15);
+}
+
+#[test]
+fn complex_step_newton_method_test() {
+    // f(x) = e^x - 2, root ln(2)
+    let f = |z: Complex64| z.exp() - 2.0;
+    let result = complex_step_newton_method(f, 1.0, 1e-14, 100);
+    assert!((result.root.unwrap() - 2.0_f64.ln()).abs() < 1e-14);
+    assert!((result.derivative - 2.0).abs() < 1e-14);
+    assert!(result.derivative_error < 1e-14);
+}
+
+#[test]
+fn complex_step_newton_method_polynomial_test() {
+    // f(x) = x^3 - 2x - 5
+    let f = |z: Complex64| z.powi(3) - z * 2.0 - 5.0;
+    let result = complex_step_newton_method(f, 2.0, 1e-14, 100);
+    assert!((result.root.unwrap() - 2.0945514815423265).abs() < 1e-14);
+}
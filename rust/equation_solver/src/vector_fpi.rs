@@ -0,0 +1,179 @@
+/// The vector norm used to measure the residual `G(x) - x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorNorm {
+    /// The sum of absolute values.
+    L1,
+    /// The Euclidean norm.
+    L2,
+    /// The largest absolute value.
+    Infinity,
+}
+
+impl VectorNorm {
+    /// Computes the norm of the vector `v`.
+    pub fn of(&self, v: &[f64]) -> f64 {
+        match self {
+            VectorNorm::L1 => v.iter().map(|v_i| v_i.abs()).sum(),
+            VectorNorm::L2 => dot(v, v).sqrt(),
+            VectorNorm::Infinity => v.iter().fold(0.0, |max, v_i| max.max(v_i.abs())),
+        }
+    }
+}
+
+/// The result of [`vector_fixed_point_iter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorFpiResult {
+    /// The last iterate, the fixed point if `converged` is true.
+    pub x: Vec<f64>,
+    /// The number of evaluations of the map `G`.
+    pub iterations: usize,
+    /// Whether the residual dropped below the tolerance.
+    pub converged: bool,
+    /// The norm of the last residual `G(x) - x`.
+    pub residual: f64,
+    /// The norms of all residuals, one per evaluation of `G`.
+    pub residual_history: Vec<f64>,
+    /// The observed contraction factor, the geometric mean of the ratios of the last (up to 5)
+    /// successive residuals. `None` if fewer than two residuals are available.
+    pub contraction_factor: Option<f64>,
+    /// The a-posteriori error estimate `q / (1 - q) * residual` for the observed contraction
+    /// factor `q`. `None` if the iteration does not contract.
+    pub error_estimate: Option<f64>,
+}
+
+/// Performs fixed-point iteration `x = G(x)` for a vector map `G: R^n -> R^n`.
+///
+/// With `anderson_depth > 0` the iteration is accelerated by Anderson mixing of the latest
+/// `anderson_depth` iterates: the next iterate is `G(x_k) - dG * gamma`, where `gamma` minimizes
+/// `|f_k - dF * gamma|_2` over the differences `dF`, `dG` of the latest residuals `f = G(x) - x`
+/// and map values. `anderson_depth = 0` gives the plain iteration `x_{k+1} = G(x_k)`.
+///
+/// # Arguments
+///
+/// * `g` - The map whose fixed point is to be found.
+/// * `initial_guess` - The initial guess for the fixed point.
+/// * `tolerance` - The tolerance value for the residual norm.
+/// * `max_iterations` - The maximum number of evaluations of `g`.
+/// * `norm` - The norm used to measure the residual.
+/// * `anderson_depth` - The number of previous iterates used by the Anderson acceleration.
+///
+/// # Returns
+///
+/// The last iterate together with the convergence and contraction diagnostics.
+pub fn vector_fixed_point_iter(
+    g: impl Fn(&[f64]) -> Vec<f64>,
+    initial_guess: &[f64],
+    tolerance: f64,
+    max_iterations: usize,
+    norm: VectorNorm,
+    anderson_depth: usize,
+) -> VectorFpiResult {
+    let mut x = initial_guess.to_vec();
+    let mut residual_history = vec![];
+    let mut delta_f: Vec<Vec<f64>> = vec![];
+    let mut delta_g: Vec<Vec<f64>> = vec![];
+    let mut previous: Option<(Vec<f64>, Vec<f64>)> = None;
+
+    for _ in 0..max_iterations {
+        let g_x = g(&x);
+        let f: Vec<f64> = g_x.iter().zip(&x).map(|(g_i, x_i)| g_i - x_i).collect();
+        residual_history.push(norm.of(&f));
+
+        if anderson_depth > 0 {
+            if let Some((f_prev, g_prev)) = previous.take() {
+                delta_f.push(f.iter().zip(&f_prev).map(|(a, b)| a - b).collect());
+                delta_g.push(g_x.iter().zip(&g_prev).map(|(a, b)| a - b).collect());
+                if delta_f.len() > anderson_depth {
+                    delta_f.remove(0);
+                    delta_g.remove(0);
+                }
+            }
+        }
+
+        if residual_history[residual_history.len() - 1] < tolerance {
+            return fpi_result(g_x, residual_history, true);
+        }
+
+        x = g_x.clone();
+        if !delta_f.is_empty() {
+            let gamma = least_squares(&delta_f, &f);
+            for (gamma_j, dg_j) in gamma.iter().zip(&delta_g) {
+                x.iter_mut()
+                    .zip(dg_j)
+                    .for_each(|(x_i, dg_ij)| *x_i -= gamma_j * dg_ij);
+            }
+        }
+        previous = Some((f, g_x));
+    }
+
+    fpi_result(x, residual_history, false)
+}
+
+fn fpi_result(x: Vec<f64>, residual_history: Vec<f64>, converged: bool) -> VectorFpiResult {
+    let n = residual_history.len();
+    let residual = residual_history.last().copied().unwrap_or(f64::NAN);
+    let contraction_factor = if n >= 2 {
+        let ratios = (n - 1).min(5);
+        let q =
+            (residual_history[n - 1] / residual_history[n - 1 - ratios]).powf(1.0 / ratios as f64);
+        Some(q).filter(|q| q.is_finite())
+    } else {
+        None
+    };
+    let error_estimate = contraction_factor
+        .filter(|&q| q < 1.0)
+        .map(|q| q / (1.0 - q) * residual);
+    VectorFpiResult {
+        x,
+        iterations: n,
+        converged,
+        residual,
+        residual_history,
+        contraction_factor,
+        error_estimate,
+    }
+}
+
+/// Solves `min |rhs - A * gamma|_2` for the columns of `A` by modified Gram-Schmidt QR.
+///
+/// Columns that are (nearly) linearly dependent on the previous ones get `gamma_j = 0`.
+fn least_squares(columns: &[Vec<f64>], rhs: &[f64]) -> Vec<f64> {
+    let m = columns.len();
+    let mut q: Vec<Vec<f64>> = vec![];
+    let mut r = vec![vec![0.0; m]; m];
+    let mut kept = vec![];
+    for (j, column) in columns.iter().enumerate() {
+        let mut v = column.clone();
+        let initial_norm = dot(&v, &v).sqrt();
+        for (k, q_k) in q.iter().enumerate() {
+            let r_kj = dot(q_k, &v);
+            r[k][j] = r_kj;
+            v.iter_mut()
+                .zip(q_k)
+                .for_each(|(v_i, q_ki)| *v_i -= r_kj * q_ki);
+        }
+        let norm = dot(&v, &v).sqrt();
+        if norm == 0.0 || norm <= 1e-10 * initial_norm {
+            continue;
+        }
+        r[q.len()][j] = norm;
+        v.iter_mut().for_each(|v_i| *v_i /= norm);
+        q.push(v);
+        kept.push(j);
+    }
+
+    let q_t_rhs: Vec<f64> = q.iter().map(|q_k| dot(q_k, rhs)).collect();
+    let mut gamma = vec![0.0; m];
+    for i in (0..kept.len()).rev() {
+        let mut s = q_t_rhs[i];
+        for &l in &kept[i + 1..] {
+            s -= r[i][l] * gamma[l];
+        }
+        gamma[kept[i]] = s / r[i][kept[i]];
+    }
+    gamma
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a_i, b_i)| a_i * b_i).sum()
+}
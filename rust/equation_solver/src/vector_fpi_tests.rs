@@ -0,0 +1,74 @@
+use crate::vector_fpi::{vector_fixed_point_iter, VectorNorm};
+
+#[test]
+fn vector_norm_test() {
+    let v = [3.0, -4.0];
+    assert_eq!(VectorNorm::L1.of(&v), 7.0);
+    assert_eq!(VectorNorm::L2.of(&v), 5.0);
+    assert_eq!(VectorNorm::Infinity.of(&v), 4.0);
+}
+
+#[test]
+fn vector_fpi_linear_test() {
+    // G(x) = diag(0.9, 0.5) x + (0.1, 1.0), fixed point (1, 2)
+    let g = |x: &[f64]| vec![0.9 * x[0] + 0.1, 0.5 * x[1] + 1.0];
+    let tol = 1e-10;
+    let r = vector_fixed_point_iter(g, &[0.0, 0.0], tol, 1000, VectorNorm::Infinity, 0);
+    assert!(r.converged);
+    assert!((r.x[0] - 1.0).abs() < 1e-8);
+    assert!((r.x[1] - 2.0).abs() < 1e-8);
+    assert!(r.residual < tol);
+    assert_eq!(r.residual_history.len(), r.iterations);
+    assert!((r.contraction_factor.unwrap() - 0.9).abs() < 1e-6);
+    assert!(r.error_estimate.unwrap() < 1e-8);
+}
+
+#[test]
+fn vector_fpi_anderson_test() {
+    // G(x, y) = (cos(y) / 2, sin(x) / 2 + 0.5)
+    let g = |x: &[f64]| vec![0.5 * x[1].cos(), 0.5 * x[0].sin() + 0.5];
+    let tol = 1e-12;
+    let plain = vector_fixed_point_iter(g, &[0.0, 0.0], tol, 200, VectorNorm::L2, 0);
+    let anderson = vector_fixed_point_iter(g, &[0.0, 0.0], tol, 200, VectorNorm::L2, 3);
+    assert!(plain.converged);
+    assert!(anderson.converged);
+    assert!(anderson.iterations < plain.iterations);
+    for r in [&plain, &anderson] {
+        let gx = g(&r.x);
+        assert!((gx[0] - r.x[0]).abs() < 1e-11);
+        assert!((gx[1] - r.x[1]).abs() < 1e-11);
+    }
+}
+
+#[test]
+fn vector_fpi_anderson_linear_test() {
+    // Anderson acceleration solves a linear n-dimensional problem in about n + 1 steps
+    let n = 5;
+    let g = |x: &[f64]| {
+        (0..n)
+            .map(|i| {
+                let neighbours =
+                    if i > 0 { x[i - 1] } else { 0.0 } + if i + 1 < n { x[i + 1] } else { 0.0 };
+                0.45 * neighbours + 1.0
+            })
+            .collect::<Vec<_>>()
+    };
+    let plain = vector_fixed_point_iter(g, &[0.0; 5], 1e-10, 1000, VectorNorm::L1, 0);
+    let anderson = vector_fixed_point_iter(g, &[0.0; 5], 1e-10, 1000, VectorNorm::L1, 5);
+    assert!(plain.converged && anderson.converged);
+    assert!(anderson.iterations <= n + 3);
+    assert!(plain.iterations > 50);
+    for (a, b) in plain.x.iter().zip(&anderson.x) {
+        assert!((a - b).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn vector_fpi_diverges_test() {
+    let g = |x: &[f64]| vec![2.0 * x[0] + 1.0, x[1]];
+    let r = vector_fixed_point_iter(g, &[1.0, 1.0], 1e-10, 20, VectorNorm::L2, 0);
+    assert!(!r.converged);
+    assert_eq!(r.iterations, 20);
+    assert!((r.contraction_factor.unwrap() - 2.0).abs() < 1e-9);
+    assert!(r.error_estimate.is_none());
+}
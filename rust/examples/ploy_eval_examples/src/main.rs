@@ -1,5 +1,5 @@
 use num_traits::pow;
-use poly_eval::poly_eval;
+use poly_eval::{poly_eval, poly_eval_compensated};
 fn main() {
     let coefficients = (0..=50).map(|_i| 1.0).collect::<Vec<_>>();
     let x = vec![1.00001];
@@ -11,6 +11,12 @@ fn main() {
         dir_result,
         result[0] - dir_result
     );
+    let comp_result = poly_eval_compensated(&coefficients, &x, None);
+    println!(
+        "Compensated Result: {}, Error {}",
+        comp_result[0],
+        comp_result[0] - dir_result
+    );
 
     let coefficients = (0..=99)
         .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
@@ -24,4 +30,10 @@ fn main() {
         dir_result,
         result[0] - dir_result
     );
+    let comp_result = poly_eval_compensated(&coefficients, &x, None);
+    println!(
+        "Compensated Result: {}, Error {}",
+        comp_result[0],
+        comp_result[0] - dir_result
+    );
 }
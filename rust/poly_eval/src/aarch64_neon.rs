@@ -1,6 +1,15 @@
+//! SIMD kernels for `aarch64` NEON.
+//!
+//! Every kernel performs exactly the same floating-point operations, in the same order, as the
+//! scalar reference called by its dispatcher for the tail of `x`, so their results are bitwise
+//! identical. Only the kernels with `fma` in their name fuse a multiply and an add, and their
+//! reference is the matching `mul_add` loop. The dispatchers pick the kernel from a
+//! [`Backend`](crate::Backend), never by detecting CPU features themselves.
+//! `test_support::assert_kernels_eq` checks each kernel against its reference.
+
 #[cfg(target_arch = "aarch64")]
 #[inline]
-pub(crate) unsafe fn neon_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result: &mut Vec<f64>) {
+pub(crate) unsafe fn neon_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
     use std::arch::aarch64::*;
 
     for i in (0..x.len()).step_by(8) {
@@ -105,3 +114,279 @@ pub(crate) unsafe fn neon_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result
         vst1q_f64_x4(ptr, y);
     }
 }
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_fma_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let mut y_ix2 = vdupq_n_f64(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j with a single rounding, notice that c and b are reversed
+            let c_jx2 = vdupq_n_f64(c[j]);
+            let base_jx2 = vdupq_n_f64(base[j - 1]);
+            let sub_0 = vsubq_f64(x_ix2, base_jx2);
+            y_ix2 = vfmaq_f64(c_jx2, sub_0, y_ix2);
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        vst1q_f64(ptr, y_ix2);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_fma_monomial_eval(x: &[f64], c: &[f64], result: &mut [f64]) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let mut y_ix2 = vdupq_n_f64(c[0]);
+
+        for c_j in &c[1..] {
+            // calc xy+c_j with a single rounding, notice that c is reversed
+            y_ix2 = vfmaq_f64(vdupq_n_f64(*c_j), x_ix2, y_ix2);
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        vst1q_f64(ptr, y_ix2);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_comp_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::aarch64::*;
+
+    // error-free transformation a + b = s + e
+    #[inline(always)]
+    unsafe fn two_sum(a: float64x2_t, b: float64x2_t) -> (float64x2_t, float64x2_t) {
+        let s = vaddq_f64(a, b);
+        let bb = vsubq_f64(s, a);
+        let e = vaddq_f64(vsubq_f64(a, vsubq_f64(s, bb)), vsubq_f64(b, bb));
+        (s, e)
+    }
+
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let mut s = vdupq_n_f64(c[0]);
+        let mut e = vdupq_n_f64(0.0);
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let c_jx2 = vdupq_n_f64(c[j]);
+            let neg_base_jx2 = vdupq_n_f64(-base[j - 1]);
+            let (t, dt) = two_sum(x_ix2, neg_base_jx2);
+            // error-free transformation t * s = p + pi
+            let p = vmulq_f64(t, s);
+            let pi = vfmaq_f64(vnegq_f64(p), t, s);
+            let (s_new, sigma) = two_sum(p, c_jx2);
+            let err = vaddq_f64(vaddq_f64(pi, sigma), vmulq_f64(s, dt));
+            e = vaddq_f64(vmulq_f64(e, t), err);
+            s = s_new;
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        vst1q_f64(ptr, vaddq_f64(s, e));
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_derivatives_eval(
+    x: &[f64],
+    c: &[f64],
+    base: &[f64],
+    result: &mut [Vec<f64>],
+) {
+    use std::arch::aarch64::*;
+
+    let k = result.len() - 1;
+    let factorials = crate::derivatives::factorials(k);
+    let mut d = vec![vdupq_n_f64(0.0); k + 1];
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        d.fill(vdupq_n_f64(0.0));
+        d[0] = vdupq_n_f64(c[0]);
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let t = vsubq_f64(x_ix2, vdupq_n_f64(base[j - 1]));
+            for m in (1..=k).rev() {
+                d[m] = vaddq_f64(vmulq_f64(d[m], t), d[m - 1]);
+            }
+            d[0] = vaddq_f64(vmulq_f64(d[0], t), vdupq_n_f64(c[j]));
+        }
+
+        for (m, row) in result.iter_mut().enumerate() {
+            let y = vmulq_f64(d[m], vdupq_n_f64(factorials[m]));
+            vst1q_f64(row.as_mut_ptr().add(i), y);
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_eval_f32(x: &[f32], c: &[f32], base: &[f32], result: &mut [f32]) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = vld1q_f32(x.as_ptr().add(i));
+        let mut y_ix4 = vdupq_n_f32(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j without fusing, notice that c and b are reversed
+            let sub_0 = vsubq_f32(x_ix4, vdupq_n_f32(base[j - 1]));
+            let mul_0 = vmulq_f32(sub_0, y_ix4);
+            y_ix4 = vaddq_f32(mul_0, vdupq_n_f32(c[j]));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        vst1q_f32(ptr, y_ix4);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_complex_eval(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    result: (&mut [f64], &mut [f64]),
+) {
+    use std::arch::aarch64::*;
+
+    for i in (0..z.0.len()).step_by(2) {
+        // load 2 z to reg, real and imaginary parts are split
+        let z_re = vld1q_f64(z.0.as_ptr().add(i));
+        let z_im = vld1q_f64(z.1.as_ptr().add(i));
+        let mut s_re = vdupq_n_f64(c.0[0]);
+        let mut s_im = vdupq_n_f64(c.1[0]);
+
+        for j in 1..c.0.len() {
+            // calc (z-b_j)s+c_j without fusing, notice that c and b are reversed
+            let t_re = vsubq_f64(z_re, vdupq_n_f64(base.0[j - 1]));
+            let t_im = vsubq_f64(z_im, vdupq_n_f64(base.1[j - 1]));
+            let p_re = vsubq_f64(vmulq_f64(t_re, s_re), vmulq_f64(t_im, s_im));
+            let p_im = vaddq_f64(vmulq_f64(t_re, s_im), vmulq_f64(t_im, s_re));
+            s_re = vaddq_f64(p_re, vdupq_n_f64(c.0[j]));
+            s_im = vaddq_f64(p_im, vdupq_n_f64(c.1[j]));
+        }
+
+        vst1q_f64(result.0.as_mut_ptr().add(i), s_re);
+        vst1q_f64(result.1.as_mut_ptr().add(i), s_im);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_batch_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::aarch64::*;
+
+    let n = c.len() / 8;
+    for (k, x_k) in x.iter().enumerate() {
+        // the lanes are 8 polynomials, x is shared
+        let x_kx2 = vdupq_n_f64(*x_k);
+        for l in (0..8).step_by(2) {
+            let mut y_lx2 = vdupq_n_f64(0.0);
+            for j in 0..n {
+                let c_jx2 = vld1q_f64(c.as_ptr().add(j * 8 + l));
+                let base_jx2 = vld1q_f64(base.as_ptr().add(j * 8 + l));
+                // calc (x-b_j)(c_j+y) without fusing, the last c is only added
+                let add_0 = vaddq_f64(c_jx2, y_lx2);
+                if j + 1 < n {
+                    y_lx2 = vmulq_f64(vsubq_f64(x_kx2, base_jx2), add_0);
+                } else {
+                    y_lx2 = add_0;
+                }
+            }
+            vst1q_f64(result.as_mut_ptr().add(k * 8 + l), y_lx2);
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_clenshaw_eval(
+    x: &[f64],
+    c: &[f64],
+    mid: f64,
+    scale: f64,
+    result: &mut [f64],
+) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        // map 2 x to t in [-1, 1]
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let t = vmulq_f64(vsubq_f64(x_ix2, vdupq_n_f64(mid)), vdupq_n_f64(scale));
+        let two_t = vaddq_f64(t, t);
+        let mut b_1 = vdupq_n_f64(0.0);
+        let mut b_2 = vdupq_n_f64(0.0);
+
+        for k in (1..c.len()).rev() {
+            // b_k = 2t b_{k+1} - b_{k+2} + c_k without fusing
+            let b_k = vsubq_f64(vmulq_f64(two_t, b_1), b_2);
+            b_2 = b_1;
+            b_1 = vaddq_f64(b_k, vdupq_n_f64(c[k]));
+        }
+
+        let y = vaddq_f64(vsubq_f64(vmulq_f64(t, b_1), b_2), vdupq_n_f64(c[0]));
+        vst1q_f64(result.as_mut_ptr().add(i), y);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_recurrence_eval(
+    x: &[f64],
+    c: &[f64],
+    (a, b, g): (&[f64], &[f64], &[f64]),
+    result: &mut [f64],
+) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let mut s_1 = vdupq_n_f64(0.0);
+        let mut s_2 = vdupq_n_f64(0.0);
+
+        for k in (0..c.len()).rev() {
+            // s_k = (a_k x + b_k) s_{k+1} - g_k s_{k+2} + c_k without fusing
+            let p = vaddq_f64(vmulq_f64(vdupq_n_f64(a[k]), x_ix2), vdupq_n_f64(b[k]));
+            let s_k = vsubq_f64(vmulq_f64(p, s_1), vmulq_f64(vdupq_n_f64(g[k]), s_2));
+            s_2 = s_1;
+            s_1 = vaddq_f64(s_k, vdupq_n_f64(c[k]));
+        }
+
+        vst1q_f64(result.as_mut_ptr().add(i), s_1);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) unsafe fn neon_rational_eval(x: &[f64], (p, q): (&[f64], &[f64]), result: &mut [f64]) {
+    use std::arch::aarch64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        let x_ix2 = vld1q_f64(x.as_ptr().add(i));
+        let mut num = vdupq_n_f64(p[0]);
+        let mut den = vdupq_n_f64(q[0]);
+
+        for j in 1..p.len() {
+            // numerator and denominator share the loads of x, without fusing
+            num = vaddq_f64(vmulq_f64(num, x_ix2), vdupq_n_f64(p[j]));
+            den = vaddq_f64(vmulq_f64(den, x_ix2), vdupq_n_f64(q[j]));
+        }
+
+        vst1q_f64(result.as_mut_ptr().add(i), vdivq_f64(num, den));
+    }
+}
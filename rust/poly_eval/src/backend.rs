@@ -0,0 +1,122 @@
+use std::sync::OnceLock;
+
+/**
+求值使用的计算后端（SIMD指令集）
+
+[`PolynomialEvaluator`](crate::PolynomialEvaluator)和其它求值函数默认使用[`Backend::selected`]，即第一次使用时检测到的
+当前CPU支持的最佳后端，也可以通过[`PolynomialEvaluator::with_backend`](crate::PolynomialEvaluator::with_backend)
+或各个求值函数的`_with_backend`版本强制使用指定的后端（例如用于测试）。
+
+# 结果的一致性
+
+每个SIMD内核都与对应的普通算法按照相同的顺序做相同的运算，求值函数对`x`的对齐部分使用SIMD内核，剩余部分使用普通算法，
+所以结果与`x`的长度无关：不使用融合乘加的后端的结果与[`Backend::Scalar`]逐位相同，使用融合乘加的后端之间的结果也逐位相同。
+只有[`PolynomialEvaluator`](crate::PolynomialEvaluator)（以及基于它的函数）有使用融合乘加的内核，
+其它求值函数在使用融合乘加的后端上使用同一指令集不融合的内核，结果与[`Backend::Scalar`]逐位相同。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// 不使用SIMD的普通算法，所有平台都支持。
+    Scalar,
+    /// `x86_64`的SSE2指令集，每次计算2个`f64`。
+    Sse2,
+    /// `x86_64`的AVX2指令集，每次计算4个`f64`。
+    Avx2,
+    /// `x86_64`的AVX2和FMA指令集，使用融合乘加，每次计算4个`f64`。
+    Avx2Fma,
+    /// `x86_64`的AVX-512F指令集，每次计算8个`f64`。
+    Avx512,
+    /// `x86_64`的AVX-512F指令集，使用融合乘加，每次计算8个`f64`。
+    Avx512Fma,
+    /// `aarch64`的NEON指令集，每次计算2个`f64`。
+    Neon,
+    /// `aarch64`的NEON指令集，使用融合乘加，每次计算2个`f64`。
+    NeonFma,
+}
+
+impl Backend {
+    /// 所有的后端，按照从慢到快的顺序排列。
+    pub const ALL: [Backend; 8] = [
+        Backend::Scalar,
+        Backend::Sse2,
+        Backend::Avx2,
+        Backend::Avx2Fma,
+        Backend::Avx512,
+        Backend::Avx512Fma,
+        Backend::Neon,
+        Backend::NeonFma,
+    ];
+
+    /**
+    检测当前CPU支持的最佳后端
+
+    支持融合乘加时优先选择使用融合乘加的后端。
+
+    # 返回值
+
+    当前CPU支持的最快的后端，不支持任何SIMD指令集时返回[`Backend::Scalar`]。
+    */
+    pub fn detect() -> Backend {
+        Backend::ALL
+            .into_iter()
+            .rev()
+            .find(|backend| backend.is_supported())
+            .unwrap_or(Backend::Scalar)
+    }
+
+    /**
+    默认使用的后端
+
+    第一次调用时通过[`Backend::detect`]检测，之后返回缓存的结果，不再重复检测CPU特性。
+    */
+    pub fn selected() -> Backend {
+        static SELECTED: OnceLock<Backend> = OnceLock::new();
+        *SELECTED.get_or_init(Backend::detect)
+    }
+
+    /**
+    判断当前CPU是否支持该后端
+
+    # 返回值
+
+    当前平台和CPU支持该后端时返回`true`。
+    */
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => is_x86_feature_detected!("sse2"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2Fma => is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 | Backend::Avx512Fma => is_x86_feature_detected!("avx512f"),
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon | Backend::NeonFma => std::arch::is_aarch64_feature_detected!("neon"),
+            _ => false,
+        }
+    }
+
+    /**
+    判断该后端是否使用融合乘加（FMA）
+
+    使用融合乘加的后端每一步只舍入一次，结果的舍入误差更小，但与不使用融合乘加的后端的结果不一定逐位相同。
+    */
+    pub fn is_fma(self) -> bool {
+        matches!(
+            self,
+            Backend::Avx2Fma | Backend::Avx512Fma | Backend::NeonFma
+        )
+    }
+
+    /// The backend of the same instruction set without fused multiply-add.
+    pub(crate) fn without_fma(self) -> Backend {
+        match self {
+            Backend::Avx2Fma => Backend::Avx2,
+            Backend::Avx512Fma => Backend::Avx512,
+            Backend::NeonFma => Backend::Neon,
+            backend => backend,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::backend::Backend;
+use crate::evaluator::PolynomialEvaluator;
+use crate::poly_eval::{fma_horner_eval, poly_eval_no_simd, reverse_coefficients};
+
+/// Scalar FMA Horner as the reference of the FMA backends.
+fn poly_eval_fma_no_simd(c: &[f64], x: &[f64], b: Option<&[f64]>) -> Vec<f64> {
+    let (c, base) = reverse_coefficients(c, b, 1);
+    let mut result = vec![0.0; x.len()];
+    fma_horner_eval(x, &c, &base, &mut result);
+    result
+}
+
+#[test]
+fn test_backend_detect() {
+    let backend = Backend::detect();
+    assert!(backend.is_supported());
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx512f") {
+        assert_eq!(backend, Backend::Avx512Fma);
+    }
+    assert!(Backend::Scalar.is_supported());
+    assert_eq!(PolynomialEvaluator::new(&[1.0], None).backend(), backend);
+    assert_eq!(Backend::selected(), backend);
+    assert_eq!(Backend::selected(), Backend::selected());
+}
+
+#[test]
+fn test_backend_all_supported_match_scalar() {
+    let c: Vec<f64> = (0..11).map(|i| 1.0 / (i as f64 + 2.0) - 0.3).collect();
+    let newton_base: Vec<f64> = (0..6).map(|i| 0.5 * i as f64 - 1.0).collect();
+    for b in [None, Some(&newton_base)] {
+        for backend in Backend::ALL {
+            let evaluator = match PolynomialEvaluator::with_backend(&c, b.map(|b| &b[..]), backend)
+            {
+                Some(evaluator) => evaluator,
+                None => continue,
+            };
+            assert_eq!(evaluator.backend(), backend);
+            for n in [0, 1, 7, 8, 9, 16, 23, 64] {
+                let x: Vec<f64> = (0..n).map(|i| 0.07 * i as f64 - 1.3).collect();
+                let expected = if backend.is_fma() {
+                    poly_eval_fma_no_simd(&c, &x, b.map(|b| &b[..]))
+                } else {
+                    poly_eval_no_simd(&c, &x, b)
+                };
+                assert_eq!(
+                    evaluator.eval(&x),
+                    expected,
+                    "backend: {:?}, n: {}",
+                    backend,
+                    n
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_backend_fma_accuracy() {
+    // (x-1)^5 near its root, x - 1 is exact and (x-1)^5 is far more accurate than the evaluation
+    let c = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+    let x: Vec<f64> = (0..64).map(|i| 1.0 + (i as f64 - 32.0) * 0.001).collect();
+    let exact: Vec<f64> = x.iter().map(|x| (x - 1.0).powi(5)).collect();
+    let error =
+        |result: &[f64]| -> f64 { result.iter().zip(&exact).map(|(r, e)| (r - e).abs()).sum() };
+    let plain = error(&poly_eval_no_simd(&c, &x, None));
+    let fma = error(&poly_eval_fma_no_simd(&c, &x, None));
+    assert!(fma < plain, "fma: {}, plain: {}", fma, plain);
+    for backend in Backend::ALL.into_iter().filter(|backend| backend.is_fma()) {
+        if let Some(evaluator) = PolynomialEvaluator::with_backend(&c, None, backend) {
+            assert_eq!(error(&evaluator.eval(&x)), fma);
+        }
+    }
+}
+
+#[test]
+fn test_backend_unsupported() {
+    #[cfg(target_arch = "x86_64")]
+    assert!(PolynomialEvaluator::with_backend(&[1.0, 2.0], None, Backend::Neon).is_none());
+    #[cfg(target_arch = "aarch64")]
+    assert!(PolynomialEvaluator::with_backend(&[1.0, 2.0], None, Backend::Avx2).is_none());
+}
+
+#[test]
+fn test_backend_scalar_forced() {
+    let evaluator = PolynomialEvaluator::with_backend(
+        &[1.0, 2.0, 3.0, 4.0],
+        Some(&[3.0, 4.0, 5.0]),
+        Backend::Scalar,
+    )
+    .unwrap();
+    let x: Vec<f64> = (3..18).map(|i| i as f64).collect();
+    assert_eq!(
+        evaluator.eval(&x),
+        vec![
+            1.0, 3.0, 11.0, 49.0, 141.0, 311.0, 583.0, 981.0, 1529.0, 2251.0, 3171.0, 4313.0,
+            5701.0, 7359.0, 9311.0,
+        ]
+    );
+}
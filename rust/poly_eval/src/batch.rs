@@ -0,0 +1,211 @@
+use crate::backend::Backend;
+use crate::evaluator::PolynomialEvaluator;
+use crate::poly_eval::{horner_eval, reverse_coefficients};
+
+/// 跨多项式使用SIMD算法的最多系数个数，系数更多时对每个多项式分别在自变量之间使用SIMD算法。
+pub const BATCH_SIMD_MAX_COEFFICIENTS: usize = 32;
+
+/**
+批量多项式求值
+
+在同一组自变量上计算多个多项式的值，例如每个传感器拟合一个低次多项式后在共同的网格上求值。
+系数矩阵按行存放，每行为一个多项式的`n`个系数；结果矩阵按行存放，第`i`行为第`i`个多项式在各个`x`处的值。
+多项式的个数不少于8个并且`n`不超过[`BATCH_SIMD_MAX_COEFFICIENTS`]时，每8个多项式一组在多项式之间使用SIMD加速的算法
+（`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON），剩余的多项式使用普通的算法；
+否则对每个多项式使用[`PolynomialEvaluator`]。两种情况都使用[`Backend::selected`](crate::Backend::selected)
+同一指令集不融合乘加的内核，所以结果与`n`无关，与[`Backend::Scalar`](crate::Backend::Scalar)的[`poly_eval`](crate::poly_eval)逐位相同。
+
+# 参数
+
+- `c`: 系数矩阵，共`c.len() / n`行，每行按照从低到高的顺序排列，长度必须是`n`的倍数。
+- `n`: 每个多项式的系数个数，必须大于0。
+- `x`: 自变量的值。
+- `b`: 可选参数，基点矩阵，每行为对应多项式的`n - 1`个基点（插值点），多项式形如
+  `c0 + (x-b0)(c1 + (x-b1)(c2 + ...))`，行数必须与系数矩阵相同。
+
+# 返回值
+
+结果矩阵，共`c.len() / n`行`x.len()`列。
+
+# 示例
+
+```
+use poly_eval::poly_eval_batch;
+// 1 + 2x and 3 + x^2
+let c = [1.0, 2.0, 0.0, 3.0, 0.0, 1.0];
+let result = poly_eval_batch(&c, 3, &[1.0, 2.0], None);
+assert_eq!(result, vec![3.0, 5.0, 4.0, 7.0]);
+```
+*/
+pub fn poly_eval_batch(c: &[f64], n: usize, x: &[f64], b: Option<&[f64]>) -> Vec<f64> {
+    let mut result = vec![0.0; c.len() / n.max(1) * x.len()];
+    poly_eval_batch_into(c, n, x, b, &mut result);
+    result
+}
+
+/**
+批量多项式求值（写入调用者提供的缓冲区）
+
+与[`poly_eval_batch`]相同，但结果写入调用者提供的`result`。
+
+# 参数
+
+- `c`: 系数矩阵，共`c.len() / n`行，每行按照从低到高的顺序排列，长度必须是`n`的倍数。
+- `n`: 每个多项式的系数个数，必须大于0。
+- `x`: 自变量的值。
+- `b`: 可选参数，基点矩阵，每行为对应多项式的`n - 1`个基点，行数必须与系数矩阵相同。
+- `result`: 存放结果矩阵的缓冲区，长度必须为`c.len() / n * x.len()`，否则会`panic`。
+*/
+pub fn poly_eval_batch_into(c: &[f64], n: usize, x: &[f64], b: Option<&[f64]>, result: &mut [f64]) {
+    batch_into(c, n, x, b, Backend::selected(), result);
+}
+
+/**
+使用指定的计算后端进行批量多项式求值
+
+与[`poly_eval_batch`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 系数矩阵，共`c.len() / n`行，每行按照从低到高的顺序排列，长度必须是`n`的倍数。
+- `n`: 每个多项式的系数个数，必须大于0。
+- `x`: 自变量的值。
+- `b`: 可选参数，基点矩阵，每行为对应多项式的`n - 1`个基点，行数必须与系数矩阵相同。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+结果矩阵，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_batch_with_backend(
+    c: &[f64],
+    n: usize,
+    x: &[f64],
+    b: Option<&[f64]>,
+    backend: Backend,
+) -> Option<Vec<f64>> {
+    if !backend.is_supported() {
+        return None;
+    }
+    let mut result = vec![0.0; c.len() / n.max(1) * x.len()];
+    batch_into(c, n, x, b, backend, &mut result);
+    Some(result)
+}
+
+fn batch_into(
+    c: &[f64],
+    n: usize,
+    x: &[f64],
+    b: Option<&[f64]>,
+    backend: Backend,
+    result: &mut [f64],
+) {
+    assert!(n > 0, "number of coefficients must be greater than 0.");
+    assert!(
+        c.len().is_multiple_of(n),
+        "coefficients' numbers must be a multiple of n."
+    );
+    let m = c.len() / n;
+    if let Some(b) = b {
+        assert_eq!(
+            b.len(),
+            m * (n - 1),
+            "base points must have n-1 columns and as many rows as coefficients."
+        );
+    }
+    assert_eq!(
+        result.len(),
+        m * x.len(),
+        "result must have c.len() / n rows and x.len() columns."
+    );
+    if x.is_empty() {
+        return;
+    }
+    let row_base = |i: usize| b.map(|b| &b[i * (n - 1)..(i + 1) * (n - 1)]);
+    // the batch kernels do not fuse, so both paths round like the plain Horner's method
+    let backend = backend.without_fma();
+
+    if n > BATCH_SIMD_MAX_COEFFICIENTS {
+        // SIMD over x for each polynomial
+        for (i, (c_i, result_i)) in c.chunks(n).zip(result.chunks_mut(x.len())).enumerate() {
+            PolynomialEvaluator::create(c_i, row_base(i), backend).eval_into(x, result_i);
+        }
+        return;
+    }
+
+    let blocks = m / 8;
+    let mut block_c = vec![0.0; n * 8];
+    let mut block_base = vec![0.0; n * 8];
+    let mut block_result = vec![0.0; x.len() * 8];
+    for block in 0..blocks {
+        // transpose 8 reversed polynomials, so that lane l holds polynomial 8 * block + l
+        for lane in 0..8 {
+            let i = block * 8 + lane;
+            let (c_i, base_i) = reverse_coefficients(&c[i * n..(i + 1) * n], row_base(i), 1);
+            for j in 0..n {
+                block_c[j * 8 + lane] = c_i[j];
+                block_base[j * 8 + lane] = base_i[j];
+            }
+        }
+        batch_eval_simd(x, &block_c, &block_base, backend, &mut block_result);
+        for lane in 0..8 {
+            let i = block * 8 + lane;
+            let result_i = &mut result[i * x.len()..(i + 1) * x.len()];
+            for (k, y) in result_i.iter_mut().enumerate() {
+                *y = block_result[k * 8 + lane];
+            }
+        }
+    }
+
+    // the remaining polynomials perform the same operations as the SIMD lanes
+    for i in blocks * 8..m {
+        let (c_i, base_i) = reverse_coefficients(&c[i * n..(i + 1) * n], row_base(i), 1);
+        horner_eval(
+            x,
+            &c_i,
+            &base_i,
+            &mut result[i * x.len()..(i + 1) * x.len()],
+        );
+    }
+}
+
+/// Evaluates 8 polynomials at once with the SIMD kernel of a supported `backend`.
+///
+/// `c` and `base` hold the reversed layout of [`reverse_coefficients`] transposed, `c[j * 8 + l]`
+/// belongs to the polynomial of lane `l`, and `result[k * 8 + l]` is its value at `x[k]`.
+fn batch_eval_simd(x: &[f64], c: &[f64], base: &[f64], backend: Backend, result: &mut [f64]) {
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_batch_eval(x, c, base, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_batch_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_batch_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_batch_eval(x, c, base, result)
+        },
+        _ => batch_eval(x, c, base, result),
+    }
+}
+
+/// Scalar version of the transposed 8-lane batch layout of [`batch_eval_simd`].
+///
+/// It is the scalar reference of the `*_batch_eval` kernels and performs the same operations as
+/// [`horner_eval`].
+pub(crate) fn batch_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    let last = c.len() / 8 - 1;
+    for (k, x_k) in x.iter().enumerate() {
+        for lane in 0..8 {
+            let mut y = 0.0;
+            for j in 0..last {
+                y = (x_k - base[j * 8 + lane]) * (c[j * 8 + lane] + y);
+            }
+            result[k * 8 + lane] = y + c[last * 8 + lane];
+        }
+    }
+}
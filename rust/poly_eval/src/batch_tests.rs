@@ -0,0 +1,133 @@
+use crate::backend::Backend;
+use crate::batch::{
+    poly_eval_batch, poly_eval_batch_into, poly_eval_batch_with_backend,
+    BATCH_SIMD_MAX_COEFFICIENTS,
+};
+use crate::poly_eval::poly_eval_no_simd;
+
+fn matrix(rows: usize, cols: usize, scale: f64) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|i| ((i * 7 % 11) as f64 - 5.0) * scale)
+        .collect()
+}
+
+#[test]
+fn test_poly_eval_batch_same_as_rows() {
+    let x: Vec<f64> = (0..13).map(|i| 0.15 * i as f64 - 0.9).collect();
+    for m in [0, 1, 7, 8, 9, 16, 21] {
+        for n in [1, 2, 5, BATCH_SIMD_MAX_COEFFICIENTS] {
+            let c = matrix(m, n, 0.1);
+            let b = matrix(m, n - 1, 0.05);
+            for b in [None, Some(&b)] {
+                let result = poly_eval_batch(&c, n, &x, b.map(|b| &b[..]));
+                assert_eq!(result.len(), m * x.len());
+                for i in 0..m {
+                    let b_i = b.map(|b| b[i * (n - 1)..(i + 1) * (n - 1)].to_vec());
+                    let expected = poly_eval_no_simd(&c[i * n..(i + 1) * n], &x, b_i.as_ref());
+                    assert_eq!(
+                        result[i * x.len()..(i + 1) * x.len()],
+                        expected,
+                        "m: {}, n: {}, row: {}",
+                        m,
+                        n,
+                        i
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_batch_high_degree() {
+    let n = BATCH_SIMD_MAX_COEFFICIENTS + 1;
+    let m = 10;
+    let c = matrix(m, n, 0.01);
+    let x: Vec<f64> = (0..19).map(|i| 0.05 * i as f64 - 0.5).collect();
+    let result = poly_eval_batch(&c, n, &x, None);
+    for i in 0..m {
+        assert_eq!(
+            result[i * x.len()..(i + 1) * x.len()],
+            poly_eval_no_simd(&c[i * n..(i + 1) * n], &x, None)
+        );
+    }
+}
+
+#[test]
+fn test_poly_eval_batch_with_backend() {
+    let x: Vec<f64> = (0..19).map(|i| 0.05 * i as f64 - 0.5).collect();
+    for n in [5, BATCH_SIMD_MAX_COEFFICIENTS + 1] {
+        let c = matrix(11, n, 0.01);
+        let b = matrix(11, n - 1, 0.02);
+        let expected: Vec<f64> = (0..11)
+            .flat_map(|i| {
+                let b_i = b[i * (n - 1)..(i + 1) * (n - 1)].to_vec();
+                poly_eval_no_simd(&c[i * n..(i + 1) * n], &x, Some(&b_i))
+            })
+            .collect();
+        for backend in Backend::ALL {
+            let result = poly_eval_batch_with_backend(&c, n, &x, Some(&b), backend);
+            if backend.is_supported() {
+                assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+            } else {
+                assert_eq!(result, None);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_batch_into() {
+    // 1 + 2x, 3 + x, with base points 1 and 2
+    let c = [1.0, 2.0, 3.0, 1.0];
+    let b = [1.0, 2.0];
+    let mut result = [0.0; 6];
+    poly_eval_batch_into(&c, 2, &[1.0, 2.0, 3.0], Some(&b), &mut result);
+    assert_eq!(result, [1.0, 3.0, 5.0, 2.0, 3.0, 4.0]);
+    assert_eq!(poly_eval_batch(&c, 2, &[], None), vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_eval_batch_not_multiple() {
+    poly_eval_batch(&[1.0, 2.0, 3.0], 2, &[1.0], None);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_eval_batch_wrong_base() {
+    poly_eval_batch(&[1.0, 2.0, 3.0, 4.0], 2, &[1.0], Some(&[1.0]));
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_batch_eval_kernels_same_as_scalar() {
+    use crate::batch::batch_eval;
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_batch_eval, avx512_batch_eval, sse2_batch_eval};
+
+    let n = 6;
+    let c = matrix(n, 8, 0.1);
+    let mut base = matrix(n, 8, 0.03);
+    base[(n - 1) * 8..].fill(0.0);
+    let x: Vec<f64> = (0..11).map(|i| 0.2 * i as f64 - 1.0).collect();
+
+    let mut expected = vec![0.0; x.len() * 8];
+    batch_eval(&x, &c, &base, &mut expected);
+
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_batch_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_batch_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_batch_eval(&x, &c, &base, r)
+            }),
+        ],
+    );
+}
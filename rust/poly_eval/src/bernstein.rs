@@ -0,0 +1,266 @@
+use crate::polynomial::Polynomial;
+
+/**
+伯恩斯坦多项式求值（de Casteljau算法）
+
+计算`c0 B0n(t) + c1 B1n(t) + ... + cn Bnn(t)`，其中`Bkn(t) = C(n, k) t^k (1-t)^(n-k)`为伯恩斯坦基函数，
+`t`为`x`从区间`[a, b]`线性映射到`[0, 1]`的值。de Casteljau算法只做凸组合，在`[a, b]`内数值上非常稳定。
+
+# 参数
+
+- `c`: 伯恩斯坦系数（控制点），个数为次数加1。
+- `x`: 自变量的值。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(0, 1)`。
+
+# 返回值
+
+计算得到的多项式值。
+
+# 示例
+
+```
+use poly_eval::poly_eval_bernstein;
+// B02 + B22 = (1-t)^2 + t^2
+let result = poly_eval_bernstein(&[1.0, 0.0, 1.0], &[0.0, 0.5, 1.0], None);
+assert_eq!(result, vec![1.0, 0.5, 1.0]);
+```
+*/
+pub fn poly_eval_bernstein(c: &[f64], x: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0.0; x.len()];
+    poly_eval_bernstein_into(c, x, domain, &mut result);
+    result
+}
+
+/**
+伯恩斯坦多项式求值（写入调用者提供的缓冲区）
+
+与[`poly_eval_bernstein`]相同，但结果写入调用者提供的`result`。
+
+# 参数
+
+- `c`: 伯恩斯坦系数（控制点），个数为次数加1。
+- `x`: 自变量的值。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(0, 1)`。
+- `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果`c`为空，结果全部为0。
+*/
+pub fn poly_eval_bernstein_into(
+    c: &[f64],
+    x: &[f64],
+    domain: Option<(f64, f64)>,
+    result: &mut [f64],
+) {
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "result must have the same length as x."
+    );
+    if c.is_empty() {
+        result.fill(0.0);
+        return;
+    }
+    let (a, b) = domain.unwrap_or((0.0, 1.0));
+    let mut work = vec![0.0; c.len()];
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let t = if domain.is_some() {
+            (x_i - a) / (b - a)
+        } else {
+            *x_i
+        };
+        *y_i = de_casteljau(c, t, &mut work);
+    }
+}
+
+/**
+伯恩斯坦多项式的分割
+
+在参数`t`处把`[0, 1]`上的伯恩斯坦多项式分割为`[0, t]`和`[t, 1]`两段，每段的系数都是相对于各自区间的，
+即左段在`s`处的值等于原多项式在`s t`处的值，右段在`s`处的值等于原多项式在`t + s (1-t)`处的值。
+带区间的多项式先把分割点映射到`[0, 1]`即可。
+
+# 参数
+
+- `c`: 伯恩斯坦系数（控制点）。
+- `t`: 分割点，通常位于`[0, 1]`内。
+
+# 返回值
+
+`(left, right)`，左右两段的伯恩斯坦系数，个数与`c`相同。
+
+# 示例
+
+```
+use poly_eval::bernstein_subdivide;
+let (left, right) = bernstein_subdivide(&[0.0, 1.0, 0.0], 0.5);
+assert_eq!(left, vec![0.0, 0.5, 0.5]);
+assert_eq!(right, vec![0.5, 0.5, 0.0]);
+```
+*/
+pub fn bernstein_subdivide(c: &[f64], t: f64) -> (Vec<f64>, Vec<f64>) {
+    if c.is_empty() {
+        return (vec![], vec![]);
+    }
+    let n = c.len();
+    let mut work = c.to_vec();
+    let mut left = Vec::with_capacity(n);
+    let mut right = vec![0.0; n];
+    left.push(work[0]);
+    right[n - 1] = work[n - 1];
+    // the first and the last points of each de Casteljau level are the control points
+    for r in 1..n {
+        for k in 0..n - r {
+            work[k] = (1.0 - t) * work[k] + t * work[k + 1];
+        }
+        left.push(work[0]);
+        right[n - 1 - r] = work[n - 1 - r];
+    }
+    (left, right)
+}
+
+/**
+伯恩斯坦多项式的升阶
+
+把`n`次多项式的伯恩斯坦系数表示为`n+1`次的伯恩斯坦系数，多项式本身不变。
+
+# 参数
+
+- `c`: 伯恩斯坦系数（控制点）。
+
+# 返回值
+
+升阶后的伯恩斯坦系数，个数比`c`多1。
+
+# 示例
+
+```
+use poly_eval::bernstein_elevate;
+assert_eq!(bernstein_elevate(&[0.0, 1.0]), vec![0.0, 0.5, 1.0]);
+```
+*/
+pub fn bernstein_elevate(c: &[f64]) -> Vec<f64> {
+    if c.is_empty() {
+        return vec![];
+    }
+    let n = c.len() as f64;
+    let mut elevated = Vec::with_capacity(c.len() + 1);
+    elevated.push(c[0]);
+    for k in 1..c.len() {
+        let w = k as f64 / n;
+        elevated.push(w * c[k - 1] + (1.0 - w) * c[k]);
+    }
+    elevated.push(c[c.len() - 1]);
+    elevated
+}
+
+/**
+伯恩斯坦系数转换为单项式系数
+
+# 参数
+
+- `c`: 伯恩斯坦系数（控制点）。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(0, 1)`，单项式系数是关于`x`（而不是`t`）的。
+
+# 返回值
+
+单项式系数，按照从低到高的顺序排列，长度与`c`相同。
+
+# 示例
+
+```
+use poly_eval::bernstein_to_monomial;
+// (1-t)^2 + t^2 = 1 - 2t + 2t^2
+assert_eq!(bernstein_to_monomial(&[1.0, 0.0, 1.0], None), vec![1.0, -2.0, 2.0]);
+```
+*/
+pub fn bernstein_to_monomial(c: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    let n = c.len();
+    // a_j = C(n, j) sum_{k <= j} (-1)^(j-k) C(j, k) c_k
+    let mut p = vec![0.0; n];
+    for (j, p_j) in p.iter_mut().enumerate() {
+        let mut sum = 0.0;
+        for (k, c_k) in c[..=j].iter().enumerate() {
+            let term = binomial(j, k) * c_k;
+            if (j - k) % 2 == 0 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+        }
+        *p_j = binomial(n - 1, j) * sum;
+    }
+
+    match domain {
+        None => p,
+        Some((a, b)) => {
+            // substitute t = (x - a) / (b - a)
+            let t = Polynomial::new(&[-a / (b - a), 1.0 / (b - a)]);
+            let mut p = Polynomial::new(&p).compose(&t).coefficients().to_vec();
+            p.resize(n, 0.0);
+            p
+        }
+    }
+}
+
+/**
+单项式系数转换为伯恩斯坦系数
+
+# 参数
+
+- `c`: 单项式系数，`c[0]`为常数项。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(0, 1)`。
+
+# 返回值
+
+伯恩斯坦系数，长度与`c`相同，可以直接传给[`poly_eval_bernstein`]。
+
+# 示例
+
+```
+use poly_eval::monomial_to_bernstein;
+// t = (B12 + 2 B22) / 2
+assert_eq!(monomial_to_bernstein(&[0.0, 1.0, 0.0], None), vec![0.0, 0.5, 1.0]);
+```
+*/
+pub fn monomial_to_bernstein(c: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    let n = c.len();
+    let c = match domain {
+        None => c.to_vec(),
+        Some((a, b)) => {
+            // substitute x = a + (b - a) t
+            let x = Polynomial::new(&[a, b - a]);
+            let mut p = Polynomial::new(c).compose(&x).coefficients().to_vec();
+            p.resize(n, 0.0);
+            p
+        }
+    };
+
+    // b_k = sum_{j <= k} C(k, j) / C(n, j) a_j
+    (0..n)
+        .map(|k| {
+            c[..=k]
+                .iter()
+                .enumerate()
+                .map(|(j, a_j)| binomial(k, j) / binomial(n - 1, j) * a_j)
+                .sum()
+        })
+        .collect()
+}
+
+/// De Casteljau's algorithm at `t`, `work` is a scratch buffer of the same length as `c`.
+pub(crate) fn de_casteljau(c: &[f64], t: f64, work: &mut [f64]) -> f64 {
+    work.copy_from_slice(c);
+    for r in 1..c.len() {
+        for k in 0..c.len() - r {
+            work[k] = (1.0 - t) * work[k] + t * work[k + 1];
+        }
+    }
+    work[0]
+}
+
+/// The binomial coefficient `C(n, k)`, exact while it fits in the mantissa.
+fn binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
@@ -0,0 +1,123 @@
+use crate::bernstein::{
+    bernstein_elevate, bernstein_subdivide, bernstein_to_monomial, monomial_to_bernstein,
+    poly_eval_bernstein, poly_eval_bernstein_into,
+};
+use crate::poly_eval::poly_eval;
+
+fn assert_close(result: &[f64], expected: &[f64], tolerance: f64) {
+    assert_eq!(result.len(), expected.len());
+    for (r, e) in result.iter().zip(expected) {
+        assert!((r - e).abs() <= tolerance * e.abs().max(1.0), "{} {}", r, e);
+    }
+}
+
+fn points() -> Vec<f64> {
+    (0..11).map(|i| 0.1 * i as f64).collect()
+}
+
+#[test]
+fn test_poly_eval_bernstein() {
+    let t = points();
+    // partition of unity
+    let result = poly_eval_bernstein(&[1.0; 7], &t, None);
+    assert_close(&result, &[1.0; 11], 1e-15);
+
+    // B13 = 3t(1-t)^2
+    let result = poly_eval_bernstein(&[0.0, 1.0, 0.0, 0.0], &t, None);
+    let expected: Vec<f64> = t.iter().map(|t| 3.0 * t * (1.0 - t) * (1.0 - t)).collect();
+    assert_close(&result, &expected, 1e-15);
+
+    // endpoints interpolate the first and the last coefficients
+    let c = [2.0, -1.0, 5.0, 3.0];
+    assert_eq!(poly_eval_bernstein(&c, &[0.0, 1.0], None), vec![2.0, 3.0]);
+    assert_eq!(
+        poly_eval_bernstein(&c, &[-1.0, 3.0], Some((-1.0, 3.0))),
+        vec![2.0, 3.0]
+    );
+}
+
+#[test]
+fn test_poly_eval_bernstein_empty() {
+    assert_eq!(poly_eval_bernstein(&[], &[1.0], None), vec![]);
+    let mut result = [1.0; 2];
+    poly_eval_bernstein_into(&[], &[0.0, 1.0], None, &mut result);
+    assert_eq!(result, [0.0; 2]);
+}
+
+#[test]
+fn test_bernstein_monomial_conversion() {
+    // (1-t)^3 = 1 - 3t + 3t^2 - t^3
+    assert_eq!(
+        bernstein_to_monomial(&[1.0, 0.0, 0.0, 0.0], None),
+        vec![1.0, -3.0, 3.0, -1.0]
+    );
+    // t^3 is the last basis polynomial
+    assert_eq!(
+        monomial_to_bernstein(&[0.0, 0.0, 0.0, 1.0], None),
+        vec![0.0, 0.0, 0.0, 1.0]
+    );
+    // x on [2, 4] has control points 2 and 4
+    assert_eq!(
+        monomial_to_bernstein(&[0.0, 1.0], Some((2.0, 4.0))),
+        vec![2.0, 4.0]
+    );
+    assert_eq!(
+        bernstein_to_monomial(&[2.0, 4.0], Some((2.0, 4.0))),
+        vec![0.0, 1.0]
+    );
+    assert_eq!(bernstein_to_monomial(&[], None), Vec::<f64>::new());
+}
+
+#[test]
+fn test_bernstein_round_trip() {
+    let c = [1.5, -2.0, 0.25, 3.0, -1.0, 0.5];
+    let domain = Some((-1.0, 2.0));
+    let x: Vec<f64> = (0..13).map(|i| 0.25 * i as f64 - 1.0).collect();
+
+    let bernstein = monomial_to_bernstein(&c, domain);
+    assert_close(
+        &poly_eval_bernstein(&bernstein, &x, domain),
+        &poly_eval(&c, &x, None),
+        1e-13,
+    );
+    assert_close(&bernstein_to_monomial(&bernstein, domain), &c, 1e-13);
+}
+
+#[test]
+fn test_bernstein_subdivide() {
+    let c = [1.0, -2.0, 4.0, 0.5, 3.0];
+    let s = points();
+    let t = 0.3;
+    let (left, right) = bernstein_subdivide(&c, t);
+    let left_x: Vec<f64> = s.iter().map(|s| s * t).collect();
+    let right_x: Vec<f64> = s.iter().map(|s| t + s * (1.0 - t)).collect();
+    assert_close(
+        &poly_eval_bernstein(&left, &s, None),
+        &poly_eval_bernstein(&c, &left_x, None),
+        1e-14,
+    );
+    assert_close(
+        &poly_eval_bernstein(&right, &s, None),
+        &poly_eval_bernstein(&c, &right_x, None),
+        1e-14,
+    );
+    assert_eq!(left[4], right[0]);
+    assert_eq!(bernstein_subdivide(&[], 0.5), (vec![], vec![]));
+}
+
+#[test]
+fn test_bernstein_elevate() {
+    let c = [1.0, -2.0, 4.0, 0.5];
+    let mut elevated = c.to_vec();
+    for _ in 0..3 {
+        elevated = bernstein_elevate(&elevated);
+    }
+    assert_eq!(elevated.len(), 7);
+    let t = points();
+    assert_close(
+        &poly_eval_bernstein(&elevated, &t, None),
+        &poly_eval_bernstein(&c, &t, None),
+        1e-14,
+    );
+    assert_eq!(bernstein_elevate(&[2.0]), vec![2.0, 2.0]);
+}
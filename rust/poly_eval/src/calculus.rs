@@ -0,0 +1,154 @@
+use crate::newton_form::{monomial_to_newton, newton_to_monomial};
+use crate::polynomial::Polynomial;
+
+impl Polynomial {
+    /**
+    求导数
+
+    # 返回值
+
+    导函数`p'(x)`，常数多项式的导数为零多项式。只需要导数在某些点的值时，
+    [`poly_eval_derivatives`](crate::poly_eval_derivatives)不需要构造导函数。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    let p = Polynomial::new(&[1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    assert_eq!(p.derivative().coefficients(), &[2.0, 6.0]);
+    ```
+    */
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::from_vec(
+            self.coefficients()
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(k, c)| c * k as f64)
+                .collect(),
+        )
+    }
+
+    /**
+    求原函数
+
+    # 返回值
+
+    常数项为0的原函数`P(x)`，满足`P'(x) = p(x)`，`P(0) = 0`。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    let p = Polynomial::new(&[2.0, 6.0]); // 2 + 6x
+    assert_eq!(p.antiderivative().coefficients(), &[0.0, 2.0, 3.0]);
+    ```
+    */
+    pub fn antiderivative(&self) -> Polynomial {
+        if self.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut c = vec![0.0; self.coefficients().len() + 1];
+        for (k, c_k) in self.coefficients().iter().enumerate() {
+            c[k + 1] = c_k / (k + 1) as f64;
+        }
+        Polynomial::from_vec(c)
+    }
+
+    /**
+    定积分
+
+    由原函数精确计算`∫[a, b] p(x) dx = P(b) - P(a)`，原函数的两个值使用[`eval`](Self::eval)一次求得，
+    不需要数值积分。
+
+    # 参数
+
+    - `a`: 积分下限。
+    - `b`: 积分上限。
+
+    # 返回值
+
+    定积分的值。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    let p = Polynomial::new(&[0.0, 0.0, 3.0]); // 3x^2
+    assert_eq!(p.integral(1.0, 2.0), 7.0);
+    ```
+    */
+    pub fn integral(&self, a: f64, b: f64) -> f64 {
+        let values = self.antiderivative().eval(&[a, b]);
+        values[1] - values[0]
+    }
+}
+
+/**
+牛顿形式多项式的导数
+
+# 参数
+
+- `c`: 牛顿形式的系数，`c[0]`为常数项。
+- `b`: 基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+导函数在同一组基点下的牛顿形式系数（比`c`少一个）。导函数只用到`b`的前`c.len() - 2`个基点，
+传给[`poly_eval`](crate::poly_eval)时需要截取`b`，即`Some(&b[..c.len() - 2].to_vec())`（`b`更短时不需要截取），
+否则基点个数多于系数个数减1会`panic`。
+
+# 示例
+
+```
+use poly_eval::{newton_derivative, poly_eval};
+// p = 1 + (x-1)(2 + 3(x-2)), p' = 2 + 3(x-2) + 3(x-1) = -7 + 6x
+let c = [1.0, 2.0, 3.0];
+let b = [1.0, 2.0];
+let d = newton_derivative(&c, &b);
+assert_eq!(poly_eval(&d, &[0.0, 1.0], Some(&b[..c.len() - 2].to_vec())), vec![-7.0, -1.0]);
+```
+*/
+pub fn newton_derivative(c: &[f64], b: &[f64]) -> Vec<f64> {
+    let p = Polynomial::new(&newton_to_monomial(c, b)).derivative();
+    let mut d = p.coefficients().to_vec();
+    d.resize(c.len().saturating_sub(1), 0.0);
+    let base = &b[..b.len().min(d.len().saturating_sub(1))];
+    monomial_to_newton(&d, base)
+}
+
+/**
+牛顿形式多项式的原函数
+
+# 参数
+
+- `c`: 牛顿形式的系数，`c[0]`为常数项。
+- `b`: 基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同，原函数多出的一个基点为0。
+
+# 返回值
+
+原函数在同一组基点下的牛顿形式系数（比`c`多一个），原函数在`b[0]`（没有基点时为0）处的值为0，
+因此第一个系数为0。
+
+# 示例
+
+```
+use poly_eval::{newton_antiderivative, poly_eval};
+// p = 2 + 6(x-1), P = 3x^2 - 4x + 1 = 0 + (x-1)(-1 + 3x)
+let c = newton_antiderivative(&[2.0, 6.0], &[1.0]);
+assert_eq!(c, vec![0.0, -1.0, 3.0]);
+assert_eq!(poly_eval(&c, &[0.0, 2.0], Some(&vec![1.0])), vec![1.0, 5.0]);
+```
+*/
+pub fn newton_antiderivative(c: &[f64], b: &[f64]) -> Vec<f64> {
+    if c.is_empty() {
+        return vec![];
+    }
+    let p = Polynomial::new(&newton_to_monomial(c, b)).antiderivative();
+    let mut a = p.coefficients().to_vec();
+    a.resize(c.len() + 1, 0.0);
+    let mut newton = monomial_to_newton(&a, b);
+    // fix the constant so that P(b0) = 0, the first Newton coefficient is P(b0)
+    newton[0] = 0.0;
+    newton
+}
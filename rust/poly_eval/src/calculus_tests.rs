@@ -0,0 +1,95 @@
+use crate::calculus::{newton_antiderivative, newton_derivative};
+use crate::derivatives::poly_eval_derivatives;
+use crate::newton_form::newton_to_monomial;
+use crate::poly_eval::poly_eval;
+use crate::polynomial::Polynomial;
+
+#[test]
+fn test_polynomial_derivative() {
+    let p = Polynomial::new(&[5.0, -1.0, 0.0, 3.0, 2.0]);
+    assert_eq!(p.derivative().coefficients(), &[-1.0, 0.0, 9.0, 8.0]);
+    assert_eq!(
+        p.derivative().derivative().coefficients(),
+        &[0.0, 18.0, 24.0]
+    );
+    assert!(Polynomial::constant(3.0).derivative().is_zero());
+    assert!(Polynomial::zero().derivative().is_zero());
+
+    let x: Vec<f64> = (0..9).map(|i| 0.25 * i as f64 - 1.0).collect();
+    let derivatives = poly_eval_derivatives(p.coefficients(), &x, None, 1);
+    assert_eq!(p.derivative().eval(&x), derivatives[1]);
+}
+
+#[test]
+fn test_polynomial_antiderivative() {
+    let p = Polynomial::new(&[1.0, 2.0, 3.0, 4.0]);
+    let a = p.antiderivative();
+    assert_eq!(a.coefficients(), &[0.0, 1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(a.derivative(), p);
+    assert!(Polynomial::zero().antiderivative().is_zero());
+}
+
+#[test]
+fn test_polynomial_integral() {
+    // ∫[0, 1] (1 + 2x + 3x^2 + 4x^3) dx = 4
+    let p = Polynomial::new(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(p.integral(0.0, 1.0), 4.0);
+    assert_eq!(p.integral(1.0, 0.0), -4.0);
+    assert_eq!(p.integral(-1.0, 2.0), 30.0 - (-1.0 + 1.0 - 1.0 + 1.0));
+    assert_eq!(p.integral(0.5, 0.5), 0.0);
+    assert_eq!(Polynomial::zero().integral(0.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_newton_derivative() {
+    let c = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let b = vec![3.0, 4.0, 5.0, 6.0];
+    let d = newton_derivative(&c, &b);
+    assert_eq!(d.len(), 4);
+    let expected = Polynomial::from_newton(&c, &b).derivative();
+    assert_eq!(
+        newton_to_monomial(&d, &b[..3]),
+        expected.coefficients().to_vec()
+    );
+    let x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    assert_eq!(poly_eval(&d, &x, Some(&b[..3].to_vec())), expected.eval(&x));
+    // the derivative of a constant or a linear polynomial
+    assert_eq!(newton_derivative(&[1.0], &[]), Vec::<f64>::new());
+    assert_eq!(newton_derivative(&[1.0, 2.0], &[7.0]), vec![2.0]);
+}
+
+#[test]
+fn test_newton_derivative_truncated_base() {
+    let c = [0.5, -1.0, 2.0, 0.25];
+    let b = [1.0, -2.0, 3.0];
+    let d = newton_derivative(&c, &b);
+    let x: Vec<f64> = (0..12).map(|i| 0.5 * i as f64 - 3.0).collect();
+    let expected = Polynomial::from_newton(&c, &b).derivative().eval(&x);
+    let result = poly_eval(&d, &x, Some(&b[..c.len() - 2].to_vec()));
+    for (r, e) in result.iter().zip(&expected) {
+        assert!((r - e).abs() <= 1e-12 * e.abs().max(1.0), "{} != {}", r, e);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_newton_derivative_full_base_panics() {
+    let c = [0.5, -1.0, 2.0, 0.25];
+    let b = vec![1.0, -2.0, 3.0];
+    let d = newton_derivative(&c, &b);
+    poly_eval(&d, &[0.0], Some(&b));
+}
+
+#[test]
+fn test_newton_antiderivative() {
+    let c = [1.0, 2.0, 3.0, 4.0];
+    let b = vec![3.0, 4.0, 5.0];
+    let a = newton_antiderivative(&c, &b);
+    assert_eq!(a.len(), 5);
+    assert_eq!(a[0], 0.0);
+    assert_eq!(newton_derivative(&a, &b), c.to_vec());
+    // P(b0) = 0
+    assert_eq!(poly_eval(&a, &[3.0], Some(&b)), vec![0.0]);
+    assert_eq!(newton_antiderivative(&[], &[]), Vec::<f64>::new());
+    assert_eq!(newton_antiderivative(&[2.0], &[]), vec![0.0, 2.0]);
+}
@@ -0,0 +1,288 @@
+use crate::backend::Backend;
+use crate::polynomial::Polynomial;
+
+/**
+切比雪夫级数求值（Clenshaw算法）
+
+计算`c0 T0(t) + c1 T1(t) + c2 T2(t) + ...`，其中`Tk`为第一类切比雪夫多项式，`t`为`x`从区间`[a, b]`
+线性映射到`[-1, 1]`的值。Clenshaw递推`b_k = c_k + 2t b_{k+1} - b_{k+2}`不需要计算各个`Tk`，数值上稳定。
+如果`x`的个数不少于8个，则对8的整数倍部分使用[`Backend::selected`](crate::Backend::selected)的SIMD内核
+（`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON），剩余部分使用普通的算法。
+
+# 参数
+
+- `c`: 切比雪夫级数的系数，`c[0]`为`T0`的系数。
+- `x`: 自变量的值。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(-1, 1)`。
+
+# 返回值
+
+计算得到的级数值。
+
+# 示例
+
+```
+use poly_eval::poly_eval_chebyshev;
+// T2(t) = 2t^2 - 1
+let result = poly_eval_chebyshev(&[0.0, 0.0, 1.0], &[0.5, 1.0], None);
+assert_eq!(result, vec![-0.5, 1.0]);
+// on [0, 2], x = 1.5 maps to t = 0.5
+assert_eq!(poly_eval_chebyshev(&[0.0, 0.0, 1.0], &[1.5], Some((0.0, 2.0))), vec![-0.5]);
+```
+*/
+pub fn poly_eval_chebyshev(c: &[f64], x: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0.0; x.len()];
+    poly_eval_chebyshev_into(c, x, domain, &mut result);
+    result
+}
+
+/**
+切比雪夫级数求值（写入调用者提供的缓冲区）
+
+与[`poly_eval_chebyshev`]相同，但结果写入调用者提供的`result`。
+
+# 参数
+
+- `c`: 切比雪夫级数的系数，`c[0]`为`T0`的系数。
+- `x`: 自变量的值。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(-1, 1)`。
+- `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果`c`为空，结果全部为0。
+*/
+pub fn poly_eval_chebyshev_into(
+    c: &[f64],
+    x: &[f64],
+    domain: Option<(f64, f64)>,
+    result: &mut [f64],
+) {
+    chebyshev_into(c, x, domain, Backend::selected(), result);
+}
+
+/**
+使用指定的计算后端进行切比雪夫级数求值
+
+与[`poly_eval_chebyshev`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 切比雪夫级数的系数，`c[0]`为`T0`的系数。
+- `x`: 自变量的值。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(-1, 1)`。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+计算得到的级数值，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_chebyshev_with_backend(
+    c: &[f64],
+    x: &[f64],
+    domain: Option<(f64, f64)>,
+    backend: Backend,
+) -> Option<Vec<f64>> {
+    if !backend.is_supported() {
+        return None;
+    }
+    if c.is_empty() || x.is_empty() {
+        return Some(vec![]);
+    }
+    let mut result = vec![0.0; x.len()];
+    chebyshev_into(c, x, domain, backend, &mut result);
+    Some(result)
+}
+
+fn chebyshev_into(
+    c: &[f64],
+    x: &[f64],
+    domain: Option<(f64, f64)>,
+    backend: Backend,
+    result: &mut [f64],
+) {
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "result must have the same length as x."
+    );
+    if c.is_empty() {
+        result.fill(0.0);
+        return;
+    }
+    let (mid, scale) = domain_map(domain);
+    let aligned = if x.len() >= 8 && backend != Backend::Scalar {
+        // use simd only when x length >= 8, and no simd for the last few elements
+        x.len() - x.len() % 8
+    } else {
+        0
+    };
+    clenshaw_eval_simd(
+        &x[..aligned],
+        c,
+        (mid, scale),
+        backend,
+        &mut result[..aligned],
+    );
+    clenshaw_eval(&x[aligned..], c, mid, scale, &mut result[aligned..]);
+}
+
+/**
+切比雪夫系数转换为单项式系数
+
+# 参数
+
+- `c`: 切比雪夫级数的系数，`c[0]`为`T0`的系数。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(-1, 1)`，单项式系数是关于`x`（而不是`t`）的。
+
+# 返回值
+
+单项式系数，按照从低到高的顺序排列，长度与`c`相同。
+
+# 示例
+
+```
+use poly_eval::chebyshev_to_monomial;
+// T3(x) = 4x^3 - 3x
+assert_eq!(chebyshev_to_monomial(&[0.0, 0.0, 0.0, 1.0], None), vec![0.0, -3.0, 0.0, 4.0]);
+```
+*/
+pub fn chebyshev_to_monomial(c: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    let mut p = vec![0.0; c.len()];
+    // T_{k+1} = 2t T_k - T_{k-1}
+    let mut t_prev = vec![0.0; c.len()];
+    let mut t_k = vec![0.0; c.len()];
+    for (k, c_k) in c.iter().enumerate() {
+        if k == 0 {
+            t_k[0] = 1.0;
+        } else if k == 1 {
+            t_prev[0] = 1.0;
+            t_k[0] = 0.0;
+            t_k[1] = 1.0;
+        } else {
+            let mut t_next = vec![0.0; c.len()];
+            for j in 0..k {
+                t_next[j + 1] += 2.0 * t_k[j];
+                t_next[j] -= t_prev[j];
+            }
+            t_prev = std::mem::replace(&mut t_k, t_next);
+        }
+        p.iter_mut().zip(&t_k).for_each(|(p, t)| *p += c_k * t);
+    }
+
+    match domain {
+        None => p,
+        Some(_) => {
+            // substitute t = (x - mid) * scale
+            let (mid, scale) = domain_map(domain);
+            let t = Polynomial::new(&[-mid * scale, scale]);
+            let mut p = Polynomial::new(&p).compose(&t).coefficients().to_vec();
+            p.resize(c.len(), 0.0);
+            p
+        }
+    }
+}
+
+/**
+单项式系数转换为切比雪夫系数
+
+# 参数
+
+- `c`: 单项式系数，`c[0]`为常数项。
+- `domain`: 可选参数，区间`(a, b)`，默认为`(-1, 1)`。
+
+# 返回值
+
+切比雪夫级数的系数，长度与`c`相同，可以直接传给[`poly_eval_chebyshev`]。
+
+# 示例
+
+```
+use poly_eval::monomial_to_chebyshev;
+// x^2 = (T0 + T2) / 2
+assert_eq!(monomial_to_chebyshev(&[0.0, 0.0, 1.0], None), vec![0.5, 0.0, 0.5]);
+```
+*/
+pub fn monomial_to_chebyshev(c: &[f64], domain: Option<(f64, f64)>) -> Vec<f64> {
+    let c = match domain {
+        None => c.to_vec(),
+        Some((a, b)) => {
+            // substitute x = mid + half t
+            let x = Polynomial::new(&[0.5 * (a + b), 0.5 * (b - a)]);
+            let mut p = Polynomial::new(c).compose(&x).coefficients().to_vec();
+            p.resize(c.len(), 0.0);
+            p
+        }
+    };
+
+    // Horner's method in the Chebyshev basis, t T_0 = T_1, t T_k = (T_{k+1} + T_{k-1}) / 2
+    let mut s = vec![0.0; c.len()];
+    for (n, c_n) in c.iter().enumerate().rev() {
+        let degree = c.len() - 1 - n;
+        let mut next = vec![0.0; c.len()];
+        for k in 0..degree {
+            if k == 0 {
+                next[1] += s[0];
+            } else {
+                next[k + 1] += 0.5 * s[k];
+                next[k - 1] += 0.5 * s[k];
+            }
+        }
+        next[0] += c_n;
+        s = next;
+    }
+    s
+}
+
+/// The map `t = (x - mid) * scale` from `[a, b]` to `[-1, 1]`.
+fn domain_map(domain: Option<(f64, f64)>) -> (f64, f64) {
+    match domain {
+        Some((a, b)) => (0.5 * (a + b), 2.0 / (b - a)),
+        None => (0.0, 1.0),
+    }
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 8.
+fn clenshaw_eval_simd(
+    x: &[f64],
+    c: &[f64],
+    (mid, scale): (f64, f64),
+    backend: Backend,
+    result: &mut [f64],
+) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_clenshaw_eval(x, c, mid, scale, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_clenshaw_eval(x, c, mid, scale, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_clenshaw_eval(x, c, mid, scale, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_clenshaw_eval(x, c, mid, scale, result)
+        },
+        _ => clenshaw_eval(x, c, mid, scale, result),
+    }
+}
+
+/// Clenshaw's recurrence for the Chebyshev series `c` at `t = (x - mid) * scale`, the scalar
+/// reference of the `*_clenshaw_eval` kernels.
+pub(crate) fn clenshaw_eval(x: &[f64], c: &[f64], mid: f64, scale: f64, result: &mut [f64]) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let t = (x_i - mid) * scale;
+        let two_t = t + t;
+        let (mut b_1, mut b_2) = (0.0, 0.0);
+        for c_k in c[1..].iter().rev() {
+            (b_1, b_2) = ((two_t * b_1 - b_2) + c_k, b_1);
+        }
+        *y_i = (t * b_1 - b_2) + c[0];
+    }
+}
@@ -0,0 +1,159 @@
+use crate::backend::Backend;
+use crate::chebyshev::{
+    chebyshev_to_monomial, clenshaw_eval, monomial_to_chebyshev, poly_eval_chebyshev,
+    poly_eval_chebyshev_into, poly_eval_chebyshev_with_backend,
+};
+use crate::poly_eval::poly_eval;
+
+fn coefficients() -> Vec<f64> {
+    (0..12).map(|i| 1.0 / (i as f64 + 1.0).powi(2)).collect()
+}
+
+#[test]
+fn test_poly_eval_chebyshev_basis() {
+    let t: Vec<f64> = (0..21).map(|i| 0.1 * i as f64 - 1.0).collect();
+    for k in 0..8 {
+        let mut c = vec![0.0; k + 1];
+        c[k] = 1.0;
+        let result = poly_eval_chebyshev(&c, &t, None);
+        for (r, t) in result.iter().zip(&t) {
+            let expected = (k as f64 * t.acos()).cos();
+            assert!((r - expected).abs() <= 1e-14, "k: {}, t: {}", k, t);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_chebyshev_domain() {
+    let c = coefficients();
+    let x: Vec<f64> = (0..17).map(|i| 2.0 + 0.25 * i as f64).collect();
+    let t: Vec<f64> = x.iter().map(|x| (x - 4.0) * 0.5).collect();
+    assert_eq!(
+        poly_eval_chebyshev(&c, &x, Some((2.0, 6.0))),
+        poly_eval_chebyshev(&c, &t, None)
+    );
+}
+
+#[test]
+fn test_poly_eval_chebyshev_simd_same_as_scalar() {
+    let c = coefficients();
+    for n in [0, 1, 7, 8, 9, 16, 30] {
+        let x: Vec<f64> = (0..n).map(|i| 0.13 * i as f64 - 1.1).collect();
+        let mut expected = vec![0.0; n];
+        clenshaw_eval(&x, &c, 0.5, 1.25, &mut expected);
+        let mut result = vec![1.0; n];
+        poly_eval_chebyshev_into(&c, &x, Some((-0.3, 1.3)), &mut result);
+        assert_eq!(result, expected, "n: {}", n);
+    }
+}
+
+#[test]
+fn test_poly_eval_chebyshev_with_backend() {
+    let c = coefficients();
+    let x: Vec<f64> = (0..30).map(|i| 0.13 * i as f64 - 1.1).collect();
+    let mut expected = vec![0.0; x.len()];
+    clenshaw_eval(&x, &c, 0.5, 1.25, &mut expected);
+    for backend in Backend::ALL {
+        let result = poly_eval_chebyshev_with_backend(&c, &x, Some((-0.3, 1.3)), backend);
+        if backend.is_supported() {
+            assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+        } else {
+            assert_eq!(result, None);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_chebyshev_empty() {
+    assert_eq!(poly_eval_chebyshev(&[], &[1.0], None), vec![]);
+    let mut result = [1.0; 2];
+    poly_eval_chebyshev_into(&[], &[0.0, 1.0], None, &mut result);
+    assert_eq!(result, [0.0; 2]);
+    assert_eq!(
+        poly_eval_chebyshev(&[3.0], &[0.2, 5.0], None),
+        vec![3.0, 3.0]
+    );
+}
+
+#[test]
+fn test_chebyshev_to_monomial() {
+    // T4 = 8x^4 - 8x^2 + 1, T5 = 16x^5 - 20x^3 + 5x
+    assert_eq!(
+        chebyshev_to_monomial(&[0.0, 0.0, 0.0, 0.0, 1.0], None),
+        vec![1.0, 0.0, -8.0, 0.0, 8.0]
+    );
+    assert_eq!(
+        chebyshev_to_monomial(&[0.0, 0.0, 0.0, 0.0, 0.0, 1.0], None),
+        vec![0.0, 5.0, 0.0, -20.0, 0.0, 16.0]
+    );
+    assert_eq!(
+        chebyshev_to_monomial(&[1.0, 2.0, 3.0], None),
+        vec![-2.0, 2.0, 6.0]
+    );
+    assert_eq!(chebyshev_to_monomial(&[], None), Vec::<f64>::new());
+    // T1 on [0, 2] is x - 1
+    assert_eq!(
+        chebyshev_to_monomial(&[0.0, 1.0], Some((0.0, 2.0))),
+        vec![-1.0, 1.0]
+    );
+}
+
+#[test]
+fn test_monomial_to_chebyshev() {
+    assert_eq!(
+        monomial_to_chebyshev(&[1.0, 0.0, -8.0, 0.0, 8.0], None),
+        vec![0.0, 0.0, 0.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        monomial_to_chebyshev(&[-2.0, 2.0, 6.0], None),
+        vec![1.0, 2.0, 3.0]
+    );
+    assert_eq!(monomial_to_chebyshev(&[], None), Vec::<f64>::new());
+    assert_eq!(
+        monomial_to_chebyshev(&[-1.0, 1.0], Some((0.0, 2.0))),
+        vec![0.0, 1.0]
+    );
+}
+
+#[test]
+fn test_chebyshev_round_trip() {
+    let c = coefficients();
+    let domain = Some((-2.0, 3.0));
+    let monomial = chebyshev_to_monomial(&c, domain);
+    let x: Vec<f64> = (0..11).map(|i| 0.5 * i as f64 - 2.0).collect();
+    let expected = poly_eval_chebyshev(&c, &x, domain);
+    for (a, e) in poly_eval(&monomial, &x, None).iter().zip(&expected) {
+        assert!((a - e).abs() <= 1e-12, "{} {}", a, e);
+    }
+    for (a, e) in monomial_to_chebyshev(&monomial, domain).iter().zip(&c) {
+        assert!((a - e).abs() <= 1e-12, "{} {}", a, e);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_clenshaw_eval_kernels_same_as_scalar() {
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_clenshaw_eval, avx512_clenshaw_eval, sse2_clenshaw_eval};
+
+    let c = coefficients();
+    let x: Vec<f64> = (0..16).map(|i| 0.11 * i as f64 - 0.7).collect();
+    let mut expected = vec![0.0; x.len()];
+    clenshaw_eval(&x, &c, 0.25, 0.8, &mut expected);
+
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_clenshaw_eval(&x, &c, 0.25, 0.8, r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_clenshaw_eval(&x, &c, 0.25, 0.8, r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_clenshaw_eval(&x, &c, 0.25, 0.8, r)
+            }),
+        ],
+    );
+}
@@ -0,0 +1,196 @@
+use crate::backend::Backend;
+use crate::poly_eval::reverse_coefficients;
+
+/**
+补偿Horner求值
+
+使用补偿Horner算法（基于无误差变换TwoSum/TwoProduct）计算多项式的值，其结果的精度相当于
+在两倍工作精度（约106位有效二进制位）下计算后再舍入到`f64`，适用于病态多项式（如在重根附近求值）。
+多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同。
+如果`x`的个数不少于8个，则对8的整数倍部分使用[`Backend::selected`](crate::Backend::selected)的SIMD内核
+（`x86_64`的AVX512F、AVX2+FMA或SSE2，`aarch64`的NEON）。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+计算得到的多项式值。
+
+# 示例
+
+```
+use poly_eval::poly_eval_compensated;
+// (x-1)^3 = -1 + 3x - 3x^2 + x^3
+let c = vec![-1.0, 3.0, -3.0, 1.0];
+let x = vec![1.0 + 1e-5];
+let result = poly_eval_compensated(&c, &x, None);
+let exact = (x[0] - 1.0).powi(3);
+assert!((result[0] - exact).abs() <= exact * f64::EPSILON);
+```
+*/
+pub fn poly_eval_compensated(c: &[f64], x: &[f64], b: Option<&[f64]>) -> Vec<f64> {
+    compensated(c, x, b, Backend::selected())
+}
+
+/**
+使用指定的计算后端进行补偿Horner求值
+
+与[`poly_eval_compensated`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+计算得到的多项式值，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_compensated_with_backend(
+    c: &[f64],
+    x: &[f64],
+    b: Option<&[f64]>,
+    backend: Backend,
+) -> Option<Vec<f64>> {
+    backend
+        .is_supported()
+        .then(|| compensated(c, x, b, backend))
+}
+
+/**
+双倍精度（double-double）多项式求值
+
+以double-double算术（每个数表示为未求值的和`hi + lo`）执行Horner算法，返回约106位精度的结果。
+参数约定与[`poly_eval`](crate::poly_eval)相同。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+计算得到的多项式值`(hi, lo)`，其中`hi`为舍入到`f64`的值，`lo`为剩余部分。
+*/
+pub fn poly_eval_double_double(c: &[f64], x: &[f64], b: Option<&[f64]>) -> Vec<(f64, f64)> {
+    if c.is_empty() {
+        return vec![];
+    }
+    let (c, base) = reverse_coefficients(c, b, 1);
+    x.iter()
+        .map(|&x_i| {
+            let (mut s_hi, mut s_lo) = (c[0], 0.0);
+            for j in 1..c.len() {
+                // t = x - b exactly as a double-double
+                let (t_hi, t_lo) = two_sum(x_i, -base[j - 1]);
+                // s = t * s
+                let (p_hi, p_lo) = two_prod(t_hi, s_hi);
+                let p_lo = p_lo + (t_hi * s_lo + t_lo * s_hi);
+                let (p_hi, p_lo) = fast_two_sum(p_hi, p_lo);
+                // s = c + s
+                let (a_hi, a_lo) = two_sum(p_hi, c[j]);
+                (s_hi, s_lo) = fast_two_sum(a_hi, a_lo + p_lo);
+            }
+            (s_hi, s_lo)
+        })
+        .collect()
+}
+
+fn compensated(c: &[f64], x: &[f64], b: Option<&[f64]>, backend: Backend) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    let (c, base) = reverse_coefficients(c, b, 4);
+    let mut result = vec![0.0; x.len()];
+    let aligned = if x.len() >= 8 && backend != Backend::Scalar {
+        // use simd for the 8x aligned part, and no simd for the last few elements
+        x.len() - x.len() % 8
+    } else {
+        0
+    };
+    comp_eval_simd(&x[..aligned], &c, &base, backend, &mut result[..aligned]);
+    comp_eval(&x[aligned..], &c, &base, &mut result[aligned..]);
+    result
+}
+
+#[cfg(test)]
+pub(crate) fn poly_eval_compensated_no_simd(c: &[f64], x: &[f64], b: Option<&[f64]>) -> Vec<f64> {
+    compensated(c, x, b, Backend::Scalar)
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 8.
+fn comp_eval_simd(x: &[f64], c: &[f64], base: &[f64], backend: Backend, result: &mut [f64]) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        // the AVX2 kernel needs FMA for TwoProduct
+        Backend::Sse2 | Backend::Avx2 => unsafe {
+            crate::x86_64::sse2_comp_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2Fma => unsafe { crate::x86_64::avx2_comp_eval(x, c, base, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_comp_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_comp_eval(x, c, base, result)
+        },
+        _ => comp_eval(x, c, base, result),
+    }
+}
+
+/// Compensated Horner over the reversed layout of [`reverse_coefficients`], the scalar reference
+/// of the `*_comp_eval` kernels.
+pub(crate) fn comp_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let mut s = c[0];
+        let mut e = 0.0;
+        for j in 1..c.len() {
+            // t + dt = x - b
+            let (t, dt) = two_sum(*x_i, -base[j - 1]);
+            // p + pi = t * s
+            let (p, pi) = two_prod(t, s);
+            // s + sigma = p + c
+            let (s_new, sigma) = two_sum(p, c[j]);
+            // accumulate the rounding errors of this step
+            e = e * t + ((pi + sigma) + s * dt);
+            s = s_new;
+        }
+        *y_i = s + e;
+    }
+}
+
+/// Error-free transformation `a + b = s + e`.
+#[inline]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Error-free transformation `a + b = s + e`, requires `|a| >= |b|`.
+#[inline]
+pub(crate) fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// Error-free transformation `a * b = p + e`.
+#[inline]
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
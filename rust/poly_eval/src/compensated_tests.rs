@@ -0,0 +1,187 @@
+use crate::backend::Backend;
+use crate::compensated::{
+    poly_eval_compensated, poly_eval_compensated_no_simd, poly_eval_compensated_with_backend,
+    poly_eval_double_double,
+};
+use crate::poly_eval::poly_eval_no_simd;
+
+#[test]
+fn test_poly_eval_compensated_with_b() {
+    let c = vec![1.0, 2.0, 3.0, 4.0];
+    let x = vec![
+        3.0, 4.0, 5.0, 6.0, 7.0, 8., 9.0, 10.0, 11.0, 12., 13.0, 14.0, 15.0, 16.0, 17.0,
+    ];
+    let b = vec![3.0, 4.0, 5.0];
+    let result = poly_eval_compensated(&c, &x, Some(&b));
+    assert_eq!(
+        result,
+        vec![
+            1.0, 3.0, 11.0, 49.0, 141.0, 311.0, 583.0, 981.0, 1529.0, 2251.0, 3171.0, 4313.0,
+            5701.0, 7359.0, 9311.0,
+        ]
+    );
+}
+
+#[test]
+fn test_poly_eval_compensated_empty() {
+    assert_eq!(poly_eval_compensated(&[], &[1.0], None), vec![]);
+    assert_eq!(poly_eval_compensated(&[1.0], &[], None), vec![]);
+    assert_eq!(
+        poly_eval_compensated(&[2.0], &[1.0, 3.0], None),
+        vec![2.0, 2.0]
+    );
+}
+
+#[test]
+fn test_poly_eval_compensated_near_multiple_root() {
+    // (x-1)^5 = -1 + 5x - 10x^2 + 10x^3 - 5x^4 + x^5, evaluated at x = 1.001, 1.002, ..., 1.019
+    let c = vec![-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+    let x: Vec<f64> = (1..=19).map(|i| 1.0 + i as f64 * 0.001).collect();
+    let expected = vec![
+        9.999999999994493e-16,
+        3.200000000000014e-14,
+        2.429999999999561e-13,
+        1.0240000000000045e-12,
+        3.124999999999667e-12,
+        7.776000000000035e-12,
+        1.680699999999874e-11,
+        3.2768000000000145e-11,
+        5.904899999999662e-11,
+        1.0000000000000044e-10,
+        1.610509999999926e-10,
+        2.488320000000011e-10,
+        3.712929999999858e-10,
+        5.378240000000024e-10,
+        7.593749999999753e-10,
+        1.0485760000000046e-09,
+        1.4198569999999599e-09,
+        1.8895680000000086e-09,
+        2.4760989999999386e-09,
+    ];
+
+    let result = poly_eval_compensated(&c, &x, None);
+    for (r, e) in result.iter().zip(&expected) {
+        assert!(((r - e) / e).abs() <= 2.0 * f64::EPSILON);
+    }
+
+    // the plain Horner's method loses most of the digits
+    let plain = poly_eval_no_simd(&c, &x, None);
+    assert!(((plain[0] - expected[0]) / expected[0]).abs() > 1e-3);
+}
+
+#[test]
+fn test_poly_eval_compensated_sum_of_powers() {
+    // 1 + x + x^2 + ... + x^50 at x = 1.00001
+    let c = vec![1.0; 51];
+    let x = vec![1.00001; 9];
+    let result = poly_eval_compensated(&c, &x, None);
+    assert!(result.iter().all(|&r| r == 51.012752082750005));
+
+    // 1 - x + x^2 - ... - x^99 at x = 1.00001
+    let c: Vec<f64> = (0..100)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+    let result = poly_eval_compensated(&c, &x, None);
+    assert!(result.iter().all(|&r| r == -0.0005002450796474905));
+}
+
+#[test]
+fn test_poly_eval_compensated_with_backend() {
+    let c: Vec<f64> = (0..13)
+        .map(|i| ((i * 7) % 5) as f64 - 2.3 + i as f64 * 0.1)
+        .collect();
+    let b: Vec<f64> = (0..9).map(|i| 0.37 * i as f64 - 1.1).collect();
+    let x: Vec<f64> = (0..24).map(|i| -1.7 + 0.173 * i as f64).collect();
+    for b in [None, Some(&b[..])] {
+        let expected = poly_eval_compensated_no_simd(&c, &x, b);
+        for backend in Backend::ALL {
+            let result = poly_eval_compensated_with_backend(&c, &x, b, backend);
+            if backend.is_supported() {
+                assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+            } else {
+                assert_eq!(result, None);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_compensated_extreme_magnitudes() {
+    // Dekker's two_prod of the SSE2 kernel overflows above 2^996 and loses bits to underflow,
+    // the results must still equal the scalar reference bit for bit
+    let huge: Vec<f64> = (0..16).map(|i| 1e301 * (1.0 + 0.37 * i as f64)).collect();
+    let tiny: Vec<f64> = (0..16).map(|i| 1e-160 * (1.0 + 0.37 * i as f64)).collect();
+    let cases = [
+        (vec![1.0, 0.5], huge.clone()),
+        (vec![-2.0, 3.0, 1e-300], huge),
+        (vec![0.0, 1e-150, 3e-151], tiny.clone()),
+        (vec![1e-300, -1e-149, 1e-160, 0.5], tiny),
+    ];
+    for (c, x) in &cases {
+        let expected: Vec<u64> = poly_eval_compensated_no_simd(c, x, None)
+            .iter()
+            .map(|y| y.to_bits())
+            .collect();
+        for backend in Backend::ALL.into_iter().filter(|b| b.is_supported()) {
+            let result: Vec<u64> = poly_eval_compensated_with_backend(c, x, None, backend)
+                .unwrap()
+                .iter()
+                .map(|y| y.to_bits())
+                .collect();
+            assert_eq!(result, expected, "backend: {:?}, c: {:?}", backend, c);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_double_double() {
+    // (x-1)^5 at x = 1 + 1/1024 is exactly 2^-50
+    let c = vec![-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+    let result = poly_eval_double_double(&c, &[1.0 + 1.0 / 1024.0], None);
+    assert_eq!(result, vec![(2.0_f64.powi(-50), 0.0)]);
+
+    // 1/3 + 1/3 x at x = 1 - 2^-30 with the tail captured in lo
+    let third = 1.0 / 3.0;
+    let x = 1.0 - 2.0_f64.powi(-30);
+    let (hi, lo) = poly_eval_double_double(&[third, third], &[x], None)[0];
+    assert_eq!(hi, third + third * x);
+    assert!(lo != 0.0 && lo.abs() <= hi.abs() * f64::EPSILON);
+
+    // with base points
+    let c = vec![1.0, 2.0, 3.0, 5.0];
+    let b = vec![3.0, 4.0, 5.0];
+    let result = poly_eval_double_double(&c, &[1.0, 8.0], Some(&b));
+    assert_eq!(result, vec![(-105.0, 0.0), (371.0, 0.0)]);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_comp_eval_kernels_same_as_scalar() {
+    use crate::compensated::comp_eval;
+    use crate::poly_eval::reverse_coefficients;
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_comp_eval, avx512_comp_eval, sse2_comp_eval};
+
+    let c: Vec<f64> = (0..11).map(|i| 1.0 / (i as f64 + 1.0) - 0.4).collect();
+    let b: Vec<f64> = (0..7).map(|i| 0.3 * i as f64).collect();
+    let x: Vec<f64> = (0..16).map(|i| 0.11 * i as f64 - 0.5).collect();
+    let (c, base) = reverse_coefficients(&c, Some(&b), 4);
+
+    let mut expected = vec![0.0; x.len()];
+    comp_eval(&x, &c, &base, &mut expected);
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_comp_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx2Fma, &|r| unsafe {
+                avx2_comp_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_comp_eval(&x, &c, &base, r)
+            }),
+        ],
+    );
+}
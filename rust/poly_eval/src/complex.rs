@@ -0,0 +1,162 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/**
+双精度复数`re + i*im`
+
+用于复数自变量、复数系数的多项式求值，参见[`poly_eval_complex`](crate::poly_eval_complex)，
+也提供了`exp`、`sin`、`cos`等解析函数，可用于复步长求导。除法使用Smith算法，避免计算`|z|^2`时溢出。
+
+# 示例
+
+```
+use poly_eval::Complex64;
+let z = Complex64::new(1.0, 2.0);
+assert_eq!(z * z.conj(), Complex64::new(5.0, 0.0));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex64 {
+    /// 实部
+    pub re: f64,
+    /// 虚部
+    pub im: f64,
+}
+
+impl Complex64 {
+    /// 创建复数`re + i*im`。
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex64 { re, im }
+    }
+
+    /// 返回共轭复数`re - i*im`。
+    pub fn conj(self) -> Self {
+        Complex64::new(self.re, -self.im)
+    }
+
+    /// 返回复数的模`|z|`。
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// 计算`e^z`。
+    pub fn exp(self) -> Self {
+        let r = self.re.exp();
+        Complex64::new(r * self.im.cos(), r * self.im.sin())
+    }
+
+    /// 计算`sin(z)`。
+    pub fn sin(self) -> Self {
+        Complex64::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    /// 计算`cos(z)`。
+    pub fn cos(self) -> Self {
+        Complex64::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    /// 用反复平方计算`z^n`。
+    pub fn powi(self, n: i32) -> Self {
+        let mut base = if n < 0 {
+            Complex64::new(1.0, 0.0) / self
+        } else {
+            self
+        };
+        let mut n = n.unsigned_abs();
+        let mut result = Complex64::new(1.0, 0.0);
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            n >>= 1;
+        }
+        result
+    }
+}
+
+impl From<f64> for Complex64 {
+    fn from(re: f64) -> Self {
+        Complex64::new(re, 0.0)
+    }
+}
+
+impl Add for Complex64 {
+    type Output = Complex64;
+    fn add(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex64 {
+    type Output = Complex64;
+    fn sub(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex64 {
+    type Output = Complex64;
+    fn mul(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex64 {
+    type Output = Complex64;
+    fn div(self, rhs: Complex64) -> Complex64 {
+        // Smith's algorithm, avoids overflow of |rhs|^2
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Complex64::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Complex64::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl Neg for Complex64 {
+    type Output = Complex64;
+    fn neg(self) -> Complex64 {
+        Complex64::new(-self.re, -self.im)
+    }
+}
+
+impl Add<f64> for Complex64 {
+    type Output = Complex64;
+    fn add(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re + rhs, self.im)
+    }
+}
+
+impl Sub<f64> for Complex64 {
+    type Output = Complex64;
+    fn sub(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re - rhs, self.im)
+    }
+}
+
+impl Mul<f64> for Complex64 {
+    type Output = Complex64;
+    fn mul(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl Div<f64> for Complex64 {
+    type Output = Complex64;
+    fn div(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re / rhs, self.im / rhs)
+    }
+}
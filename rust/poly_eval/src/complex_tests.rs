@@ -0,0 +1,32 @@
+use crate::complex::Complex64;
+
+#[test]
+fn test_complex64_ops() {
+    let a = Complex64::new(1.0, 2.0);
+    let b = Complex64::new(3.0, -4.0);
+    assert_eq!(a + b, Complex64::new(4.0, -2.0));
+    assert_eq!(a - b, Complex64::new(-2.0, 6.0));
+    assert_eq!(a * b, Complex64::new(11.0, 2.0));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(-a, Complex64::new(-1.0, -2.0));
+    assert_eq!(a.conj(), Complex64::new(1.0, -2.0));
+    assert_eq!(b.norm(), 5.0);
+    assert_eq!(Complex64::from(2.0), Complex64::new(2.0, 0.0));
+    assert_eq!(-a / 2.0 + 1.0, Complex64::new(0.5, -1.0));
+    assert_eq!(a * 2.0 - 1.0, Complex64::new(1.0, 4.0));
+
+    // Smith's division does not overflow |b|^2
+    let big = Complex64::new(1e300, 1e300);
+    assert_eq!(big / big, Complex64::new(1.0, 0.0));
+}
+
+#[test]
+fn test_complex64_functions() {
+    let z = Complex64::new(0.5, -1.5);
+    let sin_cos = z.sin() * z.sin() + z.cos() * z.cos();
+    assert!((sin_cos - 1.0).norm() < 1e-14);
+    assert!((z.exp() * (-z).exp() - 1.0).norm() < 1e-15);
+    assert_eq!(z.powi(0), Complex64::new(1.0, 0.0));
+    assert_eq!(z.powi(2), z * z);
+    assert!((z.powi(-1) * z - 1.0).norm() < 1e-15);
+}
@@ -0,0 +1,167 @@
+use crate::backend::Backend;
+use crate::poly_eval::reverse_coefficients;
+
+/**
+多项式及其各阶导数求值
+
+一次遍历系数，同时计算多项式在每个`x`处的值及其前`k`阶导数。
+多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同。
+如果`x`的个数不少于8个，则对8的整数倍部分使用[`Backend::selected`](crate::Backend::selected)的SIMD内核
+（`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON）。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `k`: 需要计算的最高阶导数。
+
+# 返回值
+
+`k + 1`行的结果，第`j`行为`j`阶导数在各个`x`处的值，第0行为多项式的值。
+
+# 示例
+
+```
+use poly_eval::poly_eval_derivatives;
+// p(x) = 1 + 2x + 3x^2, p'(x) = 2 + 6x, p''(x) = 6
+let c = vec![1.0, 2.0, 3.0];
+let x = vec![1.0, 2.0];
+let result = poly_eval_derivatives(&c, &x, None, 2);
+assert_eq!(result, vec![vec![6.0, 17.0], vec![8.0, 14.0], vec![6.0, 6.0]]);
+```
+*/
+pub fn poly_eval_derivatives(c: &[f64], x: &[f64], b: Option<&[f64]>, k: usize) -> Vec<Vec<f64>> {
+    derivatives(c, x, b, k, Backend::selected())
+}
+
+/**
+使用指定的计算后端计算多项式及其各阶导数的值
+
+与[`poly_eval_derivatives`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `k`: 需要计算的最高阶导数。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+`k + 1`行的结果，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_derivatives_with_backend(
+    c: &[f64],
+    x: &[f64],
+    b: Option<&[f64]>,
+    k: usize,
+    backend: Backend,
+) -> Option<Vec<Vec<f64>>> {
+    backend
+        .is_supported()
+        .then(|| derivatives(c, x, b, k, backend))
+}
+
+fn derivatives(
+    c: &[f64],
+    x: &[f64],
+    b: Option<&[f64]>,
+    k: usize,
+    backend: Backend,
+) -> Vec<Vec<f64>> {
+    if c.is_empty() || x.is_empty() {
+        return vec![vec![]; k + 1];
+    }
+    let (c, base) = reverse_coefficients(c, b, 4);
+    let aligned = if x.len() >= 8 && backend != Backend::Scalar {
+        // use simd for the 8x aligned part, and no simd for the last few elements
+        x.len() - x.len() % 8
+    } else {
+        0
+    };
+    let mut result = vec![vec![0.0; aligned]; k + 1];
+    derivatives_eval_simd(&x[..aligned], &c, &base, backend, &mut result);
+    let mut tail = vec![vec![0.0; x.len() - aligned]; k + 1];
+    derivatives_eval(&x[aligned..], &c, &base, &mut tail);
+    result
+        .iter_mut()
+        .zip(tail)
+        .for_each(|(row, tail_row)| row.extend(tail_row));
+    result
+}
+
+#[cfg(test)]
+pub(crate) fn poly_eval_derivatives_no_simd(
+    c: &[f64],
+    x: &[f64],
+    b: Option<&[f64]>,
+    k: usize,
+) -> Vec<Vec<f64>> {
+    derivatives(c, x, b, k, Backend::Scalar)
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 8.
+fn derivatives_eval_simd(
+    x: &[f64],
+    c: &[f64],
+    base: &[f64],
+    backend: Backend,
+    result: &mut [Vec<f64>],
+) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_derivatives_eval(x, c, base, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_derivatives_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_derivatives_eval(x, c, base, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_derivatives_eval(x, c, base, result)
+        },
+        _ => derivatives_eval(x, c, base, result),
+    }
+}
+
+/// The factorials `0!, 1!, ..., k!` used to turn Taylor coefficients into derivatives.
+pub(crate) fn factorials(k: usize) -> Vec<f64> {
+    let mut factorials = vec![1.0; k + 1];
+    for m in 1..=k {
+        factorials[m] = factorials[m - 1] * m as f64;
+    }
+    factorials
+}
+
+/// Horner's method for the Taylor coefficients `d[m] = p^(m)(x) / m!` over the reversed layout of
+/// [`reverse_coefficients`], with `result.len() - 1` derivatives, the scalar reference of the
+/// `*_derivatives_eval` kernels.
+pub(crate) fn derivatives_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [Vec<f64>]) {
+    let k = result.len() - 1;
+    let factorials = factorials(k);
+    let mut d = vec![0.0; k + 1];
+    for (i, x_i) in x.iter().enumerate() {
+        d.fill(0.0);
+        d[0] = c[0];
+        for j in 1..c.len() {
+            let t = x_i - base[j - 1];
+            for m in (1..=k).rev() {
+                d[m] = d[m] * t + d[m - 1];
+            }
+            d[0] = d[0] * t + c[j];
+        }
+        for (m, row) in result.iter_mut().enumerate() {
+            row[i] = d[m] * factorials[m];
+        }
+    }
+}
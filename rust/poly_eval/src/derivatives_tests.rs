@@ -0,0 +1,111 @@
+use crate::backend::Backend;
+use crate::derivatives::{
+    poly_eval_derivatives, poly_eval_derivatives_no_simd, poly_eval_derivatives_with_backend,
+};
+use crate::poly_eval::poly_eval_no_simd;
+
+#[test]
+fn test_poly_eval_derivatives_with_b() {
+    // 1 + (x-3)(2 + (x-4)(3 + 4(x-5))) = 4x^3 - 45x^2 + 169x - 209
+    let c = vec![1.0, 2.0, 3.0, 4.0];
+    let b = vec![3.0, 4.0, 5.0];
+    let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    let result = poly_eval_derivatives(&c, &x, Some(&b), 4);
+    assert_eq!(
+        result,
+        vec![
+            vec![-81.0, -19.0, 1.0, 3.0, 11.0, 49.0, 141.0, 311.0, 583.0, 981.0],
+            vec![91.0, 37.0, 7.0, 1.0, 19.0, 61.0, 127.0, 217.0, 331.0, 469.0],
+            vec![-66.0, -42.0, -18.0, 6.0, 30.0, 54.0, 78.0, 102.0, 126.0, 150.0],
+            vec![24.0; 10],
+            vec![0.0; 10],
+        ]
+    );
+}
+
+#[test]
+fn test_poly_eval_derivatives_value_same_as_poly_eval() {
+    let c = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 0.5];
+    let result = poly_eval_derivatives(&c, &x, None, 0);
+    assert_eq!(result, vec![poly_eval_no_simd(&c, &x, None)]);
+}
+
+#[test]
+fn test_poly_eval_derivatives_monomial() {
+    // p(x) = x^5, p^(m)(x) = 5!/(5-m)! x^(5-m)
+    let c = vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+    let x: Vec<f64> = (0..19).map(|i| i as f64 * 0.25 - 2.0).collect();
+    let result = poly_eval_derivatives(&c, &x, None, 6);
+    let factors = [1.0, 5.0, 20.0, 60.0, 120.0, 120.0, 0.0];
+    for (m, row) in result.iter().enumerate() {
+        for (x_i, y_i) in x.iter().zip(row) {
+            let expected = factors[m] * x_i.powi(5 - m.min(5) as i32);
+            assert!((y_i - expected).abs() <= 1e-12 * expected.abs().max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_derivatives_empty() {
+    assert_eq!(
+        poly_eval_derivatives(&[], &[1.0], None, 2),
+        vec![vec![], vec![], vec![]]
+    );
+    assert_eq!(
+        poly_eval_derivatives(&[1.0], &[], None, 1),
+        vec![vec![], vec![]]
+    );
+}
+
+#[test]
+fn test_poly_eval_derivatives_with_backend() {
+    let c: Vec<f64> = (0..13).map(|i| 1.0 / (i as f64 + 1.0) - 0.3).collect();
+    let b: Vec<f64> = (0..9).map(|i| 0.21 * i as f64 - 0.8).collect();
+    let x: Vec<f64> = (0..24).map(|i| -1.3 + 0.113 * i as f64).collect();
+    for b in [None, Some(&b[..])] {
+        let expected = poly_eval_derivatives_no_simd(&c, &x, b, 3);
+        for backend in Backend::ALL {
+            let result = poly_eval_derivatives_with_backend(&c, &x, b, 3, backend);
+            if backend.is_supported() {
+                assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+            } else {
+                assert_eq!(result, None);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_derivatives_eval_kernels_same_as_scalar() {
+    use crate::derivatives::derivatives_eval;
+    use crate::poly_eval::reverse_coefficients;
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_derivatives_eval, avx512_derivatives_eval, sse2_derivatives_eval};
+
+    let c: Vec<f64> = (0..10).map(|i| (i as f64 * 0.7).sin()).collect();
+    let b: Vec<f64> = (0..5).map(|i| 0.4 * i as f64).collect();
+    let x: Vec<f64> = (0..16).map(|i| 0.09 * i as f64 - 0.6).collect();
+    let (c, base) = reverse_coefficients(&c, Some(&b), 4);
+
+    let mut expected = vec![vec![0.0; x.len()]; 4];
+    derivatives_eval(&x, &c, &base, &mut expected);
+
+    let zero = vec![0.0; x.len()];
+    assert_kernels_eq(
+        &expected,
+        zero,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_derivatives_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_derivatives_eval(&x, &c, &base, r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_derivatives_eval(&x, &c, &base, r)
+            }),
+        ],
+    );
+}
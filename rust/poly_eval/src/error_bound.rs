@@ -0,0 +1,75 @@
+/**
+带误差界的多项式求值
+
+计算多项式的值，同时使用Higham的运行误差分析（running error analysis）给出每个结果的严格的后验舍入误差界，
+即对每个`x`保证`|计算值 - 精确值| <= 误差界`（在不发生上溢、下溢的前提下）。
+可以用`-log10(误差界 / |计算值|)`估计结果中可信的十进制有效位数，在多项式的根附近这一位数会明显减少。
+多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同，
+计算值与[`poly_eval`](crate::poly_eval)的非SIMD算法的结果相同。
+
+# 参数
+
+- `c`: 多项式的系数，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+`(计算得到的多项式值, 对应的误差界)`。
+
+# 示例
+
+```
+use poly_eval::poly_eval_with_error_bound;
+// (x-1)^3 = -1 + 3x - 3x^2 + x^3
+let c = vec![-1.0, 3.0, -3.0, 1.0];
+let x = vec![1.001, 3.0];
+let (values, bounds) = poly_eval_with_error_bound(&c, &x, None);
+assert!((values[0] - 1e-9).abs() <= bounds[0]);
+assert!((values[1] - 8.0).abs() <= bounds[1]);
+```
+*/
+pub fn poly_eval_with_error_bound(c: &[f64], x: &[f64], b: Option<&[f64]>) -> (Vec<f64>, Vec<f64>) {
+    if c.is_empty() || x.is_empty() {
+        return (vec![], vec![]);
+    }
+
+    let mut base = b.map_or(vec![], |b| b.to_vec());
+    if !base.is_empty() && base.len() >= c.len() {
+        panic!("total basis numbers must be less than coefficients' numbers-1.");
+    }
+    base.resize(c.len() - 1, 0.0);
+
+    // unit roundoff
+    let u = f64::EPSILON / 2.0;
+    // the rounding errors of computing mu itself, about 6 operations per step
+    let k = (6 * c.len() + 8) as f64;
+    let gamma = k * u / (1.0 - k * u);
+
+    let mut values = Vec::with_capacity(x.len());
+    let mut bounds = Vec::with_capacity(x.len());
+    for x_i in x {
+        let mut s = c[c.len() - 1];
+        let mut mu = 0.0;
+        for (c_j, b_j) in c.iter().rev().skip(1).zip(base.iter().rev()) {
+            let t = x_i - b_j;
+            let p = t * s;
+            s = p + c_j;
+            // |e'| <= (1+u)|t||e| + u(|t s| + |p| + |s'|), with |t s| <= (1+u)|p|,
+            // and x - b is exact when b is zero
+            let t_error = if *b_j == 0.0 {
+                0.0
+            } else {
+                (1.0 + u) * p.abs()
+            };
+            mu = (1.0 + u) * t.abs() * mu + u * (t_error + p.abs() + s.abs());
+        }
+        values.push(s);
+        bounds.push(if mu == 0.0 {
+            0.0
+        } else {
+            (mu * (1.0 + gamma)).next_up()
+        });
+    }
+    (values, bounds)
+}
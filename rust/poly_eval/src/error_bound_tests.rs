@@ -0,0 +1,70 @@
+use crate::compensated::poly_eval_compensated_no_simd;
+use crate::error_bound::poly_eval_with_error_bound;
+use crate::poly_eval::poly_eval_no_simd;
+
+#[test]
+fn test_poly_eval_with_error_bound_same_values_as_no_simd() {
+    let c = vec![1.0, 2.0, 3.0, 5.0];
+    let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 8.0, 0.1, -2.7];
+    let b = vec![3.0, 4.0, 5.0];
+    for b in [None, Some(&b)] {
+        let (values, bounds) = poly_eval_with_error_bound(&c, &x, b.map(|b| &b[..]));
+        assert_eq!(values, poly_eval_no_simd(&c, &x, b));
+        assert!(bounds.iter().all(|&e| e >= 0.0));
+    }
+}
+
+#[test]
+fn test_poly_eval_with_error_bound_empty() {
+    assert_eq!(
+        poly_eval_with_error_bound(&[], &[1.0], None),
+        (vec![], vec![])
+    );
+    assert_eq!(
+        poly_eval_with_error_bound(&[1.0], &[], None),
+        (vec![], vec![])
+    );
+}
+
+#[test]
+fn test_poly_eval_with_error_bound_constant() {
+    // a constant is exact
+    let (values, bounds) = poly_eval_with_error_bound(&[3.5], &[1.0, 2.0], None);
+    assert_eq!(values, vec![3.5, 3.5]);
+    assert_eq!(bounds, vec![0.0, 0.0]);
+}
+
+#[test]
+fn test_poly_eval_with_error_bound_contains_error() {
+    // (x-1)^5 near its root, compared with the compensated result which is accurate to ~1 ulp
+    let c = vec![-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+    let x: Vec<f64> = (0..40).map(|i| 0.99 + i as f64 * 0.0005).collect();
+    let (values, bounds) = poly_eval_with_error_bound(&c, &x, None);
+    let accurate = poly_eval_compensated_no_simd(&c, &x, None);
+    for ((value, bound), exact) in values.iter().zip(&bounds).zip(&accurate) {
+        let error = (value - exact).abs() - exact.abs() * f64::EPSILON;
+        assert!(error <= *bound);
+        // the bound is not pessimistic by more than a few orders of magnitude
+        assert!(*bound < 1e-13);
+    }
+    // close to the root none of the digits are trustworthy
+    assert!(bounds[20] > values[20].abs());
+    // far from the root the bound is small relative to the value
+    let (values, bounds) = poly_eval_with_error_bound(&c, &[3.0], None);
+    assert_eq!(values[0], 32.0);
+    assert!(bounds[0] < 32.0 * 1e-13);
+}
+
+#[test]
+fn test_poly_eval_with_error_bound_with_b() {
+    // 1 + (x-0.1)(2 + (x-0.2)(3 + (x-0.3) 4)) in Newton form
+    let c = vec![1.0, 2.0, 3.0, 4.0];
+    let b = vec![0.1, 0.2, 0.3];
+    let x: Vec<f64> = (0..20).map(|i| -1.0 + 0.1 * i as f64).collect();
+    let (values, bounds) = poly_eval_with_error_bound(&c, &x, Some(&b));
+    let accurate = poly_eval_compensated_no_simd(&c, &x, Some(&b));
+    for ((value, bound), exact) in values.iter().zip(&bounds).zip(&accurate) {
+        assert!((value - exact).abs() - exact.abs() * f64::EPSILON <= *bound);
+        assert!(*bound <= 1e-13 * exact.abs().max(1.0));
+    }
+}
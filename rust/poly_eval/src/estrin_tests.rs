@@ -0,0 +1,84 @@
+use crate::estrin::{poly_eval_auto, poly_eval_estrin, EvalScheme};
+use crate::poly_eval::{poly_eval, poly_eval_no_simd};
+
+#[test]
+fn test_poly_eval_estrin_exact() {
+    let c = [1.0, 2.0, 3.0, 4.0];
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(
+        poly_eval_estrin(&c, &x),
+        vec![10.0, 49.0, 142.0, 313.0, 586.0]
+    );
+    // odd number of coefficients, the highest one is unpaired
+    assert_eq!(
+        poly_eval_estrin(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0]),
+        vec![129.0]
+    );
+    assert_eq!(poly_eval_estrin(&[7.0], &[2.0, 3.0]), vec![7.0, 7.0]);
+}
+
+#[test]
+fn test_poly_eval_estrin_same_as_horner() {
+    for degree in [0, 1, 2, 5, 15, 16, 17, 31, 50, 99, 256, 500] {
+        let c: Vec<f64> = (0..=degree)
+            .map(|i| if i % 3 == 0 { 1.0 } else { -0.5 } / (i as f64 + 1.0))
+            .collect();
+        let x: Vec<f64> = (0..9).map(|i| 0.2 * i as f64 - 0.95).collect();
+        let expected = poly_eval_no_simd(&c, &x, None);
+        let result = poly_eval_estrin(&c, &x);
+        for (r, e) in result.iter().zip(&expected) {
+            assert!(
+                (r - e).abs() <= 1e-14 * e.abs().max(1.0),
+                "degree: {}, {} {}",
+                degree,
+                r,
+                e
+            );
+        }
+    }
+}
+
+#[test]
+fn test_eval_scheme_choose() {
+    assert_eq!(EvalScheme::choose(1, 1), EvalScheme::Horner);
+    assert_eq!(
+        EvalScheme::choose(EvalScheme::ESTRIN_MIN_DEGREE, 1),
+        EvalScheme::Estrin
+    );
+    assert_eq!(
+        EvalScheme::choose(500, EvalScheme::ESTRIN_MAX_POINTS - 1),
+        EvalScheme::Estrin
+    );
+    assert_eq!(
+        EvalScheme::choose(500, EvalScheme::ESTRIN_MAX_POINTS),
+        EvalScheme::Horner
+    );
+}
+
+#[test]
+fn test_poly_eval_auto() {
+    let c: Vec<f64> = (0..=100).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+    let x = [0.5, -0.25];
+    assert_eq!(poly_eval_auto(&c, &x, None), poly_eval_estrin(&c, &x));
+    assert_eq!(
+        poly_eval_auto(&c, &x, Some(&[0.0])),
+        poly_eval_estrin(&c, &x)
+    );
+
+    // Newton form and many points use Horner
+    let b = vec![0.5, 0.25];
+    assert_eq!(
+        poly_eval_auto(&c, &x, Some(&b)),
+        poly_eval(&c, &x, Some(&b))
+    );
+    let x: Vec<f64> = (0..16).map(|i| 0.05 * i as f64).collect();
+    assert_eq!(poly_eval_auto(&c, &x, None), poly_eval(&c, &x, None));
+    assert_eq!(poly_eval_auto(&[], &x, None), vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_eval_auto_too_many_base() {
+    let c = vec![1.0; 30];
+    poly_eval_auto(&c, &[0.5], Some(&[0.0; 30]));
+}
@@ -0,0 +1,199 @@
+use crate::backend::Backend;
+use crate::poly_eval::{fma_horner_eval, horner_eval, reverse_coefficients};
+
+/**
+可复用的多项式求值器
+
+构造时一次性完成系数和基点的反转与补齐（SIMD算法要求系数个数为4的倍数），并使用[`Backend::selected`]作为计算后端，
+之后可以对同一个多项式反复求值而不再重复准备系数，[`eval_into`](Self::eval_into)不分配任何内存。
+多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同。
+
+# 示例
+
+```
+use poly_eval::PolynomialEvaluator;
+let evaluator = PolynomialEvaluator::new(&[1.0, 2.0, 3.0], None);
+let mut result = [0.0; 3];
+evaluator.eval_into(&[1.0, 2.0, 3.0], &mut result);
+assert_eq!(result, [6.0, 17.0, 34.0]);
+assert_eq!(evaluator.eval(&[0.0]), vec![1.0]);
+println!("backend: {:?}", evaluator.backend());
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialEvaluator {
+    // reversed coefficients, padded with leading zeros to a multiple of 4
+    c: Vec<f64>,
+    // reversed base points, base[j] belongs to c[j], with a trailing 0
+    base: Vec<f64>,
+    // all base points are 0, the FMA kernels skip the subtraction
+    monomial: bool,
+    backend: Backend,
+}
+
+impl PolynomialEvaluator {
+    /**
+    创建多项式求值器
+
+    # 参数
+
+    - `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+    - `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同，
+      基点个数多于多项式系数个数减1则会`panic`。
+    */
+    pub fn new(c: &[f64], b: Option<&[f64]>) -> Self {
+        Self::create(c, b, Backend::selected())
+    }
+
+    /**
+    使用指定的计算后端创建多项式求值器
+
+    与[`new`](Self::new)相同，但强制使用`backend`，参见[`Backend`]。
+
+    # 参数
+
+    - `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+    - `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+    - `backend`: 强制使用的计算后端。
+
+    # 返回值
+
+    当前CPU不支持`backend`时返回`None`。
+    */
+    pub fn with_backend(c: &[f64], b: Option<&[f64]>, backend: Backend) -> Option<Self> {
+        if !backend.is_supported() {
+            return None;
+        }
+        Some(Self::create(c, b, backend))
+    }
+
+    /// Creates the evaluator without checking that the backend is supported.
+    pub(crate) fn create(c: &[f64], b: Option<&[f64]>, backend: Backend) -> Self {
+        let (c, base) = reverse_coefficients(c, b, 4);
+        let monomial = base.iter().all(|b| *b == 0.0);
+        PolynomialEvaluator {
+            c,
+            base,
+            monomial,
+            backend,
+        }
+    }
+
+    /// 返回求值器使用的计算后端。
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /**
+    计算多项式的值，并写入调用者提供的缓冲区
+
+    如果`x`的个数不少于8个，则对8的整数倍部分使用SIMD加速的算法，剩余部分使用普通的算法。
+
+    # 参数
+
+    - `x`: 自变量的值。
+    - `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果多项式没有系数，结果全部为0。
+    */
+    pub fn eval_into(&self, x: &[f64], result: &mut [f64]) {
+        assert_eq!(
+            x.len(),
+            result.len(),
+            "result must have the same length as x."
+        );
+        if self.c.is_empty() {
+            result.fill(0.0);
+            return;
+        }
+
+        let aligned = if x.len() >= 8 && self.backend != Backend::Scalar {
+            // use simd only when x length >= 8, and no simd for the last few elements
+            x.len() - x.len() % 8
+        } else {
+            0
+        };
+        self.eval_simd(&x[..aligned], &mut result[..aligned]);
+        self.eval_scalar(&x[aligned..], &mut result[aligned..]);
+    }
+
+    /**
+    计算多项式的值
+
+    # 参数
+
+    - `x`: 自变量的值。
+
+    # 返回值
+
+    计算得到的多项式值，如果多项式没有系数，则返回空的结果。
+    */
+    pub fn eval(&self, x: &[f64]) -> Vec<f64> {
+        if self.c.is_empty() {
+            return vec![];
+        }
+        let mut result = vec![0.0; x.len()];
+        self.eval_into(x, &mut result);
+        result
+    }
+
+    /// Whether the polynomial has no coefficients.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.c.is_empty()
+    }
+
+    /// Evaluates with the SIMD kernel of the backend, the length of `x` must be a multiple of 8.
+    pub(crate) fn eval_simd(&self, x: &[f64], result: &mut [f64]) {
+        if x.is_empty() {
+            return;
+        }
+
+        // the backend is supported, it has been checked on construction
+        match self.backend {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => unsafe { crate::x86_64::sse2_eval(x, &self.c, &self.base, result) },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => unsafe { crate::x86_64::avx2_eval(x, &self.c, &self.base, result) },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 => unsafe {
+                crate::x86_64::avx512_eval(x, &self.c, &self.base, result)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2Fma if self.monomial => unsafe {
+                crate::x86_64::avx2_fma_monomial_eval(x, &self.c, result)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2Fma => unsafe {
+                crate::x86_64::avx2_fma_eval(x, &self.c, &self.base, result)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512Fma if self.monomial => unsafe {
+                crate::x86_64::avx512_fma_monomial_eval(x, &self.c, result)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512Fma => unsafe {
+                crate::x86_64::avx512_fma_eval(x, &self.c, &self.base, result)
+            },
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => unsafe {
+                crate::aarch64_neon::neon_eval(x, &self.c, &self.base, result)
+            },
+            #[cfg(target_arch = "aarch64")]
+            Backend::NeonFma if self.monomial => unsafe {
+                crate::aarch64_neon::neon_fma_monomial_eval(x, &self.c, result)
+            },
+            #[cfg(target_arch = "aarch64")]
+            Backend::NeonFma => unsafe {
+                crate::aarch64_neon::neon_fma_eval(x, &self.c, &self.base, result)
+            },
+            _ => self.eval_scalar(x, result),
+        }
+    }
+
+    /// Evaluates without SIMD, with the same rounding as the SIMD kernel of the backend.
+    fn eval_scalar(&self, x: &[f64], result: &mut [f64]) {
+        if self.backend.is_fma() {
+            fma_horner_eval(x, &self.c, &self.base, result);
+        } else {
+            horner_eval(x, &self.c, &self.base, result);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::evaluator::PolynomialEvaluator;
+use crate::poly_eval::{poly_eval_into, poly_eval_no_simd};
+
+#[test]
+fn test_evaluator_eval_into() {
+    let evaluator = PolynomialEvaluator::new(&[1.0, 2.0, 3.0, 4.0], Some(&[3.0, 4.0, 5.0]));
+    let x = [
+        3.0, 4.0, 5.0, 6.0, 7.0, 8., 9.0, 10.0, 11.0, 12., 13.0, 14.0, 15.0, 16.0, 17.0,
+    ];
+    let mut result = [0.0; 15];
+    evaluator.eval_into(&x, &mut result);
+    assert_eq!(
+        result,
+        [
+            1.0, 3.0, 11.0, 49.0, 141.0, 311.0, 583.0, 981.0, 1529.0, 2251.0, 3171.0, 4313.0,
+            5701.0, 7359.0, 9311.0,
+        ]
+    );
+}
+
+#[test]
+fn test_evaluator_reuse() {
+    let c: Vec<f64> = (0..13).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+    let b: Vec<f64> = (0..5).map(|i| 0.25 * i as f64).collect();
+    let evaluator = PolynomialEvaluator::new(&c, Some(&b));
+    let mut result = vec![0.0; 32];
+    for k in 0..4 {
+        let x: Vec<f64> = (0..32).map(|i| 0.01 * (i + k) as f64 - 0.2).collect();
+        evaluator.eval_into(&x, &mut result);
+        let expected = poly_eval_no_simd(&c, &x, Some(&b));
+        for (r, e) in result.iter().zip(&expected) {
+            assert!((r - e).abs() <= 1e-15 * e.abs().max(1.0));
+        }
+        assert_eq!(evaluator.eval(&x), result);
+    }
+}
+
+#[test]
+fn test_evaluator_empty() {
+    let evaluator = PolynomialEvaluator::new(&[], None);
+    assert_eq!(evaluator.eval(&[1.0, 2.0]), vec![]);
+    let mut result = [1.0, 1.0];
+    evaluator.eval_into(&[1.0, 2.0], &mut result);
+    assert_eq!(result, [0.0, 0.0]);
+
+    let evaluator = PolynomialEvaluator::new(&[1.0], None);
+    assert_eq!(evaluator.eval(&[]), vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_evaluator_should_panic_when_result_length_differs() {
+    let evaluator = PolynomialEvaluator::new(&[1.0, 2.0], None);
+    let mut result = [0.0; 2];
+    evaluator.eval_into(&[1.0, 2.0, 3.0], &mut result);
+}
+
+#[test]
+#[should_panic]
+fn test_evaluator_should_panic_when_b_length_equal_c() {
+    PolynomialEvaluator::new(&[1.0, 2.0, 3.0], Some(&[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn test_poly_eval_into() {
+    let c = [1.0, 2.0, 3.0, 5.0];
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0, 8.0, 1.0, 2.0, 3.0, 4.0, 5.0, 8.0];
+    let b = [3.0, 4.0, 5.0];
+    let mut result = [0.0; 12];
+    poly_eval_into(&c, &x, Some(&b), &mut result);
+    assert_eq!(
+        result,
+        [-105.0, -25.0, 1.0, 3.0, 11.0, 371.0, -105.0, -25.0, 1.0, 3.0, 11.0, 371.0]
+    );
+}
@@ -0,0 +1,130 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/**
+闭区间`[lo, hi]`
+
+区间的四则运算先按照默认的舍入方式计算端点，再向外扩大一个ULP，所以结果一定包含精确的结果，
+用于得到多项式值域的可靠包围，参见[`poly_eval_interval`](crate::poly_eval_interval)。
+
+# 示例
+
+```
+use poly_eval::Interval;
+let x = Interval::new(1.0, 2.0);
+let y = x * x - x;
+assert!(y.lo <= -1.0 && y.hi >= 3.0);
+assert!(y.contains(0.0));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    /// 下界
+    pub lo: f64,
+    /// 上界
+    pub hi: f64,
+}
+
+impl Interval {
+    /// 创建区间`[lo, hi]`，`lo`大于`hi`则会`panic`。
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "lo must not be greater than hi.");
+        Interval { lo, hi }
+    }
+
+    /// 只包含一个点的区间`[x, x]`。
+    pub fn point(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    /// 返回区间的宽度`hi - lo`（向上舍入）。
+    pub fn width(self) -> f64 {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// 返回区间内靠近中点的一个浮点数。
+    pub fn midpoint(self) -> f64 {
+        (0.5 * self.lo + 0.5 * self.hi).clamp(self.lo, self.hi)
+    }
+
+    /// 判断`x`是否在区间内。
+    pub fn contains(self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// 返回两个区间的交集，没有交集时返回`None`。
+    pub fn intersect(self, other: Interval) -> Option<Interval> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        (lo <= hi).then_some(Interval { lo, hi })
+    }
+
+    /// 包含`[lo, hi]`的区间，两个端点都向外扩大一个ULP以覆盖舍入误差。
+    fn outward(lo: f64, hi: f64) -> Self {
+        Interval {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(x: f64) -> Self {
+        Interval::point(x)
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo = products.iter().fold(f64::INFINITY, |m, p| m.min(*p));
+        let hi = products.iter().fold(f64::NEG_INFINITY, |m, p| m.max(*p));
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+    /// 除数区间不能包含0，否则会`panic`。
+    fn div(self, rhs: Interval) -> Interval {
+        assert!(!rhs.contains(0.0), "divisor must not contain zero.");
+        let quotients = [
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ];
+        let lo = quotients.iter().fold(f64::INFINITY, |m, q| m.min(*q));
+        let hi = quotients.iter().fold(f64::NEG_INFINITY, |m, q| m.max(*q));
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
@@ -2,15 +2,135 @@
 //#![feature(avx512_target_feature)]
 
 mod aarch64_neon;
+mod backend;
+mod batch;
+mod bernstein;
+mod calculus;
+mod chebyshev;
+mod compensated;
+mod complex;
+mod derivatives;
+mod error_bound;
+mod estrin;
+mod evaluator;
+mod interval;
+mod newton_form;
+mod orthogonal;
+mod parallel;
 mod poly_eval;
+mod poly_eval_complex;
+mod poly_eval_f32;
+mod poly_eval_interval;
+mod polynomial;
+mod polynomial_division;
+mod rational;
+#[cfg(test)]
+mod test_support;
 mod x86_64;
-pub use poly_eval::poly_eval;
+pub use backend::Backend;
+pub use batch::{
+    poly_eval_batch, poly_eval_batch_into, poly_eval_batch_with_backend,
+    BATCH_SIMD_MAX_COEFFICIENTS,
+};
+pub use bernstein::{
+    bernstein_elevate, bernstein_subdivide, bernstein_to_monomial, monomial_to_bernstein,
+    poly_eval_bernstein, poly_eval_bernstein_into,
+};
+pub use calculus::{newton_antiderivative, newton_derivative};
+pub use chebyshev::{
+    chebyshev_to_monomial, monomial_to_chebyshev, poly_eval_chebyshev, poly_eval_chebyshev_into,
+    poly_eval_chebyshev_with_backend,
+};
+pub use compensated::{
+    poly_eval_compensated, poly_eval_compensated_with_backend, poly_eval_double_double,
+};
+pub use complex::Complex64;
+pub use derivatives::{poly_eval_derivatives, poly_eval_derivatives_with_backend};
+pub use error_bound::poly_eval_with_error_bound;
+pub use estrin::{poly_eval_auto, poly_eval_estrin, EvalScheme};
+pub use evaluator::PolynomialEvaluator;
+pub use interval::Interval;
+pub use newton_form::{monomial_to_newton, newton_to_monomial, taylor_shift};
+pub use orthogonal::{
+    orthogonal_polynomial, poly_eval_orthogonal, poly_eval_orthogonal_into,
+    poly_eval_orthogonal_with_backend, OrthogonalBasis,
+};
+pub use parallel::{poly_eval_parallel, DEFAULT_CHUNK_SIZE};
+pub use poly_eval::{poly_eval, poly_eval_into};
+pub use poly_eval_complex::{
+    poly_eval_complex, poly_eval_complex_split, poly_eval_complex_with_backend,
+    poly_eval_real_at_complex,
+};
+pub use poly_eval_f32::{poly_eval_f32, poly_eval_f32_into, poly_eval_f32_with_backend};
+pub use poly_eval_interval::{poly_eval_interval, EnclosureMethod};
+pub use polynomial::Polynomial;
+pub use rational::RationalFunction;
+
+#[cfg(test)]
+mod backend_tests;
+
+#[cfg(test)]
+mod batch_tests;
+
+#[cfg(test)]
+mod bernstein_tests;
+
+#[cfg(test)]
+mod calculus_tests;
+
+#[cfg(test)]
+mod chebyshev_tests;
+
+#[cfg(test)]
+mod compensated_tests;
+
+#[cfg(test)]
+mod complex_tests;
+
+#[cfg(test)]
+mod derivatives_tests;
+
+#[cfg(test)]
+mod error_bound_tests;
+
+#[cfg(test)]
+mod estrin_tests;
+
+#[cfg(test)]
+mod evaluator_tests;
+
+#[cfg(test)]
+mod newton_form_tests;
+
+#[cfg(test)]
+mod orthogonal_tests;
+
+#[cfg(test)]
+mod parallel_tests;
 
 #[cfg(test)]
 mod poly_eval_tests;
 
+#[cfg(test)]
+mod poly_eval_complex_tests;
+
+#[cfg(test)]
+mod poly_eval_f32_tests;
+
+#[cfg(test)]
+mod poly_eval_interval_tests;
+
 #[cfg(test)]
 mod poly_eval_no_simd_tests;
 
 #[cfg(test)]
 mod poly_eval_simd_tests;
+
+#[cfg(test)]
+mod polynomial_division_tests;
+
+#[cfg(test)]
+mod polynomial_tests;
+
+#[cfg(test)]
+mod rational_tests;
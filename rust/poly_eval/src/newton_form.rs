@@ -0,0 +1,149 @@
+use crate::polynomial::Polynomial;
+
+/**
+牛顿形式转换为单项式（幂基）系数
+
+把`c0 + (x-b0)(c1 + (x-b1)(c2 + ...))`从最内层开始逐层乘以`(x - b_j)`展开，
+只使用加法和乘法，系数和基点为小整数或二进制小数时结果是精确的。
+
+# 参数
+
+- `c`: 牛顿形式的系数，`c[0]`为常数项。
+- `b`: 基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同，不足的自动以0补充，
+  多于系数个数减1则会`panic`。
+
+# 返回值
+
+单项式系数，按照从低到高的顺序排列，长度与`c`相同。
+
+# 示例
+
+```
+use poly_eval::newton_to_monomial;
+// 1 + (x-3)(2 + (x-4)(3 + 4(x-5)))
+let c = newton_to_monomial(&[1.0, 2.0, 3.0, 4.0], &[3.0, 4.0, 5.0]);
+assert_eq!(c, vec![-209.0, 169.0, -45.0, 4.0]);
+```
+*/
+pub fn newton_to_monomial(c: &[f64], b: &[f64]) -> Vec<f64> {
+    let b = padded_base(c, b);
+    let Some(last) = c.last() else {
+        return vec![];
+    };
+    let mut p = vec![0.0; c.len()];
+    p[0] = *last;
+    for j in (0..c.len() - 1).rev() {
+        // p = p * (x - b_j) + c_j, p has c.len() - 1 - j coefficients before the step
+        let n = c.len() - 1 - j;
+        for k in (1..=n).rev() {
+            p[k] = p[k - 1] - b[j] * p[k];
+        }
+        p[0] = c[j] - b[j] * p[0];
+    }
+    p
+}
+
+/**
+单项式（幂基）系数转换为牛顿形式
+
+依次用`(x - b0)`、`(x - b1)`……做综合除法，每次的余数就是对应的牛顿形式系数。
+
+# 参数
+
+- `c`: 单项式系数，`c[0]`为常数项。
+- `b`: 目标牛顿形式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+牛顿形式的系数，长度与`c`相同，可以直接传给[`poly_eval`](crate::poly_eval)。
+
+# 示例
+
+```
+use poly_eval::{monomial_to_newton, poly_eval};
+let c = [1.0, 2.0, 3.0, 4.0, 5.0];
+let b = vec![3.0, 4.0, 5.0, 6.0];
+let newton = monomial_to_newton(&c, &b);
+assert_eq!(newton, vec![547.0, 1046.0, 536.0, 94.0, 5.0]);
+assert_eq!(poly_eval(&newton, &[2.0], Some(&b)), poly_eval(&c, &[2.0], None));
+```
+*/
+pub fn monomial_to_newton(c: &[f64], b: &[f64]) -> Vec<f64> {
+    let b = padded_base(c, b);
+    let mut p = c.to_vec();
+    for (j, b_j) in b.iter().enumerate() {
+        // divide p[j..] by (x - b_j), the remainder p(b_j) moves to p[j], the quotient to p[j+1..]
+        for k in (j..c.len() - 1).rev() {
+            p[k] += b_j * p[k + 1];
+        }
+    }
+    p
+}
+
+/**
+泰勒平移（移动展开中心）
+
+计算`p(x + a)`的单项式系数，也就是`p`在`a`处按`(x - a)`的幂展开的系数（泰勒系数）。
+
+# 参数
+
+- `c`: 单项式系数，`c[0]`为常数项。
+- `a`: 平移量（新的展开中心）。
+
+# 返回值
+
+`p(x + a)`的单项式系数，长度与`c`相同。
+
+# 示例
+
+```
+use poly_eval::taylor_shift;
+// 1 + 2x + x^2 = (x + 1)^2, so p(x - 1) = x^2
+assert_eq!(taylor_shift(&[1.0, 2.0, 1.0], -1.0), vec![0.0, 0.0, 1.0]);
+```
+*/
+pub fn taylor_shift(c: &[f64], a: f64) -> Vec<f64> {
+    monomial_to_newton(c, &vec![a; c.len().saturating_sub(1)])
+}
+
+/// Pads the base points with zeros to `c.len() - 1`, panics if there are too many.
+pub(crate) fn padded_base(c: &[f64], b: &[f64]) -> Vec<f64> {
+    if !b.is_empty() && b.len() >= c.len() {
+        panic!("total basis numbers must be less than coefficients' numbers-1.");
+    }
+    let mut base = b.to_vec();
+    base.resize(c.len().saturating_sub(1), 0.0);
+    base
+}
+
+impl Polynomial {
+    /**
+    转换为牛顿形式的系数
+
+    # 参数
+
+    - `b`: 牛顿形式的基点（插值点），规则与[`monomial_to_newton`]相同。
+
+    # 返回值
+
+    牛顿形式的系数，长度为`degree() + 1`，零多项式返回空的结果。
+    */
+    pub fn to_newton(&self, b: &[f64]) -> Vec<f64> {
+        monomial_to_newton(self.coefficients(), b)
+    }
+
+    /**
+    泰勒平移
+
+    # 参数
+
+    - `a`: 平移量。
+
+    # 返回值
+
+    多项式`p(x + a)`。
+    */
+    pub fn taylor_shift(&self, a: f64) -> Polynomial {
+        Polynomial::from_vec(taylor_shift(self.coefficients(), a))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::newton_form::{monomial_to_newton, newton_to_monomial, taylor_shift};
+use crate::poly_eval::poly_eval;
+use crate::polynomial::Polynomial;
+
+// the expected values are generated by test_result_gen.ipynb with exact rational arithmetic
+
+#[test]
+fn test_newton_to_monomial() {
+    assert_eq!(
+        newton_to_monomial(&[1.0, 2.0, 3.0, 4.0], &[3.0, 4.0, 5.0]),
+        vec![-209.0, 169.0, -45.0, 4.0]
+    );
+    assert_eq!(
+        newton_to_monomial(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0, 6.0]),
+        vec![1591.0, -1541.0, 550.0, -86.0, 5.0]
+    );
+    // the missing base point is 0
+    assert_eq!(
+        newton_to_monomial(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0]),
+        vec![-209.0, -131.0, 190.0, -56.0, 5.0]
+    );
+    assert_eq!(
+        newton_to_monomial(&[0.5, -1.5, 0.25, 2.0, -0.75], &[0.5, -1.0, 2.5, 0.25]),
+        vec![3.859375, -6.140625, -2.8125, 3.6875, -0.75]
+    );
+    assert_eq!(newton_to_monomial(&[], &[]), Vec::<f64>::new());
+    assert_eq!(newton_to_monomial(&[2.0], &[]), vec![2.0]);
+}
+
+#[test]
+fn test_monomial_to_newton() {
+    assert_eq!(
+        monomial_to_newton(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0, 6.0]),
+        vec![547.0, 1046.0, 536.0, 94.0, 5.0]
+    );
+    assert_eq!(
+        monomial_to_newton(&[0.5, -1.5, 0.25, 2.0, -0.75], &[0.5, -1.0, 2.5, 0.25]),
+        vec![0.015625, 0.34375, -0.0625, 0.3125, -0.75]
+    );
+    assert_eq!(monomial_to_newton(&[], &[]), Vec::<f64>::new());
+}
+
+#[test]
+fn test_newton_round_trip() {
+    let c = [0.3, -1.7, 2.2, 0.9, -0.4, 1.1];
+    let b = [0.25, -0.6, 1.4, 0.8];
+    let newton = monomial_to_newton(&c, &b);
+    let x: Vec<f64> = (0..10).map(|i| 0.2 * i as f64 - 1.0).collect();
+    let from_newton = poly_eval(&newton, &x, Some(&b.to_vec()));
+    let from_monomial = poly_eval(&c, &x, None);
+    for (a, e) in from_newton.iter().zip(&from_monomial) {
+        assert!((a - e).abs() <= 1e-13, "{} {}", a, e);
+    }
+    for (a, e) in newton_to_monomial(&newton, &b).iter().zip(&c) {
+        assert!((a - e).abs() <= 1e-13, "{} {}", a, e);
+    }
+}
+
+#[test]
+fn test_taylor_shift() {
+    assert_eq!(
+        taylor_shift(&[1.0, 2.0, 3.0, 4.0, 5.0], 3.0),
+        vec![547.0, 668.0, 309.0, 64.0, 5.0]
+    );
+    assert_eq!(
+        taylor_shift(&[1.0, 2.0, 3.0, 4.0, 5.0], -0.5),
+        vec![0.5625, -0.5, 4.5, -6.0, 5.0]
+    );
+    assert_eq!(taylor_shift(&[], 1.0), Vec::<f64>::new());
+    let p = Polynomial::new(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(
+        p.taylor_shift(3.0),
+        p.compose(&Polynomial::new(&[3.0, 1.0]))
+    );
+    assert_eq!(p.taylor_shift(3.0).taylor_shift(-3.0), p);
+}
+
+#[test]
+fn test_polynomial_to_newton() {
+    let p = Polynomial::from_newton(&[1.0, 2.0, 3.0, 4.0], &[3.0, 4.0, 5.0]);
+    assert_eq!(p.to_newton(&[3.0, 4.0, 5.0]), vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Polynomial::zero().to_newton(&[]), Vec::<f64>::new());
+}
+
+#[test]
+#[should_panic]
+fn test_monomial_to_newton_too_many_base() {
+    monomial_to_newton(&[1.0, 2.0], &[1.0, 2.0]);
+}
@@ -0,0 +1,284 @@
+use crate::backend::Backend;
+
+/**
+经典正交多项式族
+
+各族均满足三项递推关系`P_{k+1}(x) = (a_k x + b_k) P_k(x) - c_k P_{k-1}(x)`，`P_0(x) = 1`，
+使用[`OrthogonalBasis::recurrence`]可以得到递推系数（例如用于构造高斯求积的Jacobi矩阵）。
+
+- `Legendre`: 勒让德多项式，`[-1, 1]`上权函数为`1`。
+- `Hermite`: 埃尔米特多项式（物理学家约定），`H_{k+1} = 2x H_k - 2k H_{k-1}`，权函数为`exp(-x^2)`。
+- `Laguerre`: 拉盖尔多项式，`[0, ∞)`上权函数为`exp(-x)`。
+- `Jacobi`: 雅可比多项式，`[-1, 1]`上权函数为`(1-x)^alpha (1+x)^beta`，要求`alpha > -1`且`beta > -1`，
+  否则权函数不可积，[`recurrence`](OrthogonalBasis::recurrence)和求值函数会`panic`。
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrthogonalBasis {
+    Legendre,
+    Hermite,
+    Laguerre,
+    Jacobi { alpha: f64, beta: f64 },
+}
+
+impl OrthogonalBasis {
+    /**
+    三项递推系数
+
+    # 参数
+
+    - `k`: 递推的步数。
+
+    # 返回值
+
+    `(a_k, b_k, c_k)`，满足`P_{k+1}(x) = (a_k x + b_k) P_k(x) - c_k P_{k-1}(x)`，其中`c_0 = 0`。
+    `Jacobi`的`alpha`或`beta`不大于-1（或为NaN）时会`panic`。
+
+    # 示例
+
+    ```
+    use poly_eval::OrthogonalBasis;
+    // 3 P_2 = 3x P_1 - P_0
+    assert_eq!(OrthogonalBasis::Legendre.recurrence(1), (1.5, 0.0, 0.5));
+    assert_eq!(OrthogonalBasis::Hermite.recurrence(3), (2.0, 0.0, 6.0));
+    ```
+    */
+    pub fn recurrence(&self, k: usize) -> (f64, f64, f64) {
+        let k = k as f64;
+        match *self {
+            OrthogonalBasis::Legendre => ((2.0 * k + 1.0) / (k + 1.0), 0.0, k / (k + 1.0)),
+            OrthogonalBasis::Hermite => (2.0, 0.0, 2.0 * k),
+            OrthogonalBasis::Laguerre => {
+                (-1.0 / (k + 1.0), (2.0 * k + 1.0) / (k + 1.0), k / (k + 1.0))
+            }
+            OrthogonalBasis::Jacobi { alpha, beta } => {
+                assert!(
+                    alpha > -1.0 && beta > -1.0,
+                    "Jacobi parameters must satisfy alpha > -1 and beta > -1."
+                );
+                if k == 0.0 {
+                    // P_1 = ((alpha + beta + 2) x + alpha - beta) / 2, the general formula
+                    // divides by zero when alpha + beta is 0 or -1
+                    return (0.5 * (alpha + beta + 2.0), 0.5 * (alpha - beta), 0.0);
+                }
+                let s = 2.0 * k + alpha + beta;
+                let d = 2.0 * (k + 1.0) * (k + alpha + beta + 1.0) * s;
+                (
+                    (s + 1.0) * (s + 2.0) * s / d,
+                    (s + 1.0) * (alpha * alpha - beta * beta) / d,
+                    2.0 * (k + alpha) * (k + beta) * (s + 2.0) / d,
+                )
+            }
+        }
+    }
+
+    /// The recurrence coefficients used by Clenshaw's recurrence for a series of `n` terms:
+    /// `a_k`, `b_k` and `c_{k+1}` for step `k`.
+    fn clenshaw_coefficients(&self, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut a = Vec::with_capacity(n);
+        let mut b = Vec::with_capacity(n);
+        let mut c = Vec::with_capacity(n);
+        for k in 0..n {
+            let (a_k, b_k, _) = self.recurrence(k);
+            a.push(a_k);
+            b.push(b_k);
+            c.push(self.recurrence(k + 1).2);
+        }
+        (a, b, c)
+    }
+}
+
+/**
+正交多项式级数求值
+
+计算`c0 P0(x) + c1 P1(x) + c2 P2(x) + ...`，其中`Pk`为`basis`指定的正交多项式，使用推广的Clenshaw递推，
+不需要逐个计算`Pk`。如果`x`的个数不少于8个，则对8的整数倍部分使用[`Backend::selected`](crate::Backend::selected)
+的SIMD内核（`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON），剩余部分使用普通的算法。
+
+# 参数
+
+- `basis`: 正交多项式族。
+- `c`: 级数的系数，`c[0]`为`P0`的系数。
+- `x`: 自变量的值。
+
+# 返回值
+
+计算得到的级数值。
+
+# 示例
+
+```
+use poly_eval::{poly_eval_orthogonal, OrthogonalBasis};
+// H_2(x) = 4x^2 - 2, so 1 + H_2(x) = 4x^2 - 1
+let result = poly_eval_orthogonal(OrthogonalBasis::Hermite, &[1.0, 0.0, 1.0], &[0.5, 1.0]);
+assert_eq!(result, vec![0.0, 3.0]);
+```
+*/
+pub fn poly_eval_orthogonal(basis: OrthogonalBasis, c: &[f64], x: &[f64]) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0.0; x.len()];
+    poly_eval_orthogonal_into(basis, c, x, &mut result);
+    result
+}
+
+/**
+正交多项式级数求值（写入调用者提供的缓冲区）
+
+与[`poly_eval_orthogonal`]相同，但结果写入调用者提供的`result`。
+
+# 参数
+
+- `basis`: 正交多项式族。
+- `c`: 级数的系数，`c[0]`为`P0`的系数。
+- `x`: 自变量的值。
+- `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果`c`为空，结果全部为0。
+*/
+pub fn poly_eval_orthogonal_into(basis: OrthogonalBasis, c: &[f64], x: &[f64], result: &mut [f64]) {
+    orthogonal_into(basis, c, x, Backend::selected(), result);
+}
+
+/**
+使用指定的计算后端进行正交多项式级数求值
+
+与[`poly_eval_orthogonal`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `basis`: 正交多项式族。
+- `c`: 级数的系数，`c[0]`为`P0`的系数。
+- `x`: 自变量的值。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+计算得到的级数值，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_orthogonal_with_backend(
+    basis: OrthogonalBasis,
+    c: &[f64],
+    x: &[f64],
+    backend: Backend,
+) -> Option<Vec<f64>> {
+    if !backend.is_supported() {
+        return None;
+    }
+    if c.is_empty() || x.is_empty() {
+        return Some(vec![]);
+    }
+    let mut result = vec![0.0; x.len()];
+    orthogonal_into(basis, c, x, backend, &mut result);
+    Some(result)
+}
+
+fn orthogonal_into(
+    basis: OrthogonalBasis,
+    c: &[f64],
+    x: &[f64],
+    backend: Backend,
+    result: &mut [f64],
+) {
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "result must have the same length as x."
+    );
+    if c.is_empty() {
+        result.fill(0.0);
+        return;
+    }
+    let (a, b, g) = basis.clenshaw_coefficients(c.len());
+    let aligned = if x.len() >= 8 && backend != Backend::Scalar {
+        // use simd only when x length >= 8, and no simd for the last few elements
+        x.len() - x.len() % 8
+    } else {
+        0
+    };
+    recurrence_eval_simd(
+        &x[..aligned],
+        c,
+        (&a, &b, &g),
+        backend,
+        &mut result[..aligned],
+    );
+    recurrence_eval(&x[aligned..], c, (&a, &b, &g), &mut result[aligned..]);
+}
+
+/**
+正交多项式求值
+
+计算`basis`指定的`n`次正交多项式`Pn`在`x`处的值。
+
+# 参数
+
+- `basis`: 正交多项式族。
+- `n`: 多项式的次数。
+- `x`: 自变量的值。
+
+# 返回值
+
+计算得到的`Pn(x)`。
+
+# 示例
+
+```
+use poly_eval::{orthogonal_polynomial, OrthogonalBasis};
+// P_2(x) = (3x^2 - 1) / 2
+assert_eq!(orthogonal_polynomial(OrthogonalBasis::Legendre, 2, &[0.0, 1.0]), vec![-0.5, 1.0]);
+```
+*/
+pub fn orthogonal_polynomial(basis: OrthogonalBasis, n: usize, x: &[f64]) -> Vec<f64> {
+    let mut c = vec![0.0; n + 1];
+    c[n] = 1.0;
+    poly_eval_orthogonal(basis, &c, x)
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 8.
+fn recurrence_eval_simd(
+    x: &[f64],
+    c: &[f64],
+    abg: (&[f64], &[f64], &[f64]),
+    backend: Backend,
+    result: &mut [f64],
+) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_recurrence_eval(x, c, abg, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_recurrence_eval(x, c, abg, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_recurrence_eval(x, c, abg, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_recurrence_eval(x, c, abg, result)
+        },
+        _ => recurrence_eval(x, c, abg, result),
+    }
+}
+
+/// Clenshaw's recurrence `s_k = (a_k x + b_k) s_{k+1} - g_k s_{k+2} + c_k` for a series whose
+/// basis satisfies a three-term recurrence, `g_k` is `c_{k+1}` of the basis recurrence. It is the
+/// scalar reference of the `*_recurrence_eval` kernels.
+pub(crate) fn recurrence_eval(
+    x: &[f64],
+    c: &[f64],
+    (a, b, g): (&[f64], &[f64], &[f64]),
+    result: &mut [f64],
+) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let (mut s_1, mut s_2) = (0.0, 0.0);
+        for k in (0..c.len()).rev() {
+            (s_1, s_2) = (((a[k] * x_i + b[k]) * s_1 - g[k] * s_2) + c[k], s_1);
+        }
+        *y_i = s_1;
+    }
+}
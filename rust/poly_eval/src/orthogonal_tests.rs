@@ -0,0 +1,215 @@
+use crate::backend::Backend;
+use crate::chebyshev::poly_eval_chebyshev;
+use crate::orthogonal::{
+    orthogonal_polynomial, poly_eval_orthogonal, poly_eval_orthogonal_into,
+    poly_eval_orthogonal_with_backend, recurrence_eval, OrthogonalBasis,
+};
+use crate::poly_eval::poly_eval;
+
+fn points() -> Vec<f64> {
+    (0..21).map(|i| 0.1 * i as f64 - 1.0).collect()
+}
+
+fn assert_close(result: &[f64], expected: &[f64], tolerance: f64) {
+    for (r, e) in result.iter().zip(expected) {
+        assert!((r - e).abs() <= tolerance * e.abs().max(1.0), "{} {}", r, e);
+    }
+}
+
+#[test]
+fn test_legendre() {
+    let x = points();
+    // P_3 = (5x^3 - 3x) / 2, P_4 = (35x^4 - 30x^2 + 3) / 8
+    let p3 = poly_eval(&[0.0, -1.5, 0.0, 2.5], &x, None);
+    let p4 = poly_eval(&[0.375, 0.0, -3.75, 0.0, 4.375], &x, None);
+    assert_close(
+        &orthogonal_polynomial(OrthogonalBasis::Legendre, 3, &x),
+        &p3,
+        1e-15,
+    );
+    assert_close(
+        &orthogonal_polynomial(OrthogonalBasis::Legendre, 4, &x),
+        &p4,
+        1e-15,
+    );
+    // P_n(1) = 1
+    assert_close(
+        &orthogonal_polynomial(OrthogonalBasis::Legendre, 30, &[1.0, -1.0]),
+        &[1.0, 1.0],
+        1e-14,
+    );
+}
+
+#[test]
+fn test_hermite() {
+    let x = [0.0, 0.5, 1.0, 2.0, -3.0];
+    // H_3 = 8x^3 - 12x, H_4 = 16x^4 - 48x^2 + 12
+    let h3 = poly_eval(&[0.0, -12.0, 0.0, 8.0], &x, None);
+    let h4 = poly_eval(&[12.0, 0.0, -48.0, 0.0, 16.0], &x, None);
+    assert_eq!(orthogonal_polynomial(OrthogonalBasis::Hermite, 3, &x), h3);
+    assert_eq!(orthogonal_polynomial(OrthogonalBasis::Hermite, 4, &x), h4);
+}
+
+#[test]
+fn test_laguerre() {
+    let x: Vec<f64> = (0..10).map(|i| 0.7 * i as f64).collect();
+    // L_3 = (-x^3 + 9x^2 - 18x + 6) / 6
+    let l3 = poly_eval(&[1.0, -3.0, 1.5, -1.0 / 6.0], &x, None);
+    assert_close(
+        &orthogonal_polynomial(OrthogonalBasis::Laguerre, 3, &x),
+        &l3,
+        1e-14,
+    );
+    // L_n(0) = 1
+    assert_close(
+        &orthogonal_polynomial(OrthogonalBasis::Laguerre, 12, &[0.0]),
+        &[1.0],
+        1e-14,
+    );
+}
+
+#[test]
+fn test_jacobi() {
+    let x = points();
+    let c: Vec<f64> = (0..9).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+    // alpha = beta = 0 is Legendre
+    let jacobi = OrthogonalBasis::Jacobi {
+        alpha: 0.0,
+        beta: 0.0,
+    };
+    assert_close(
+        &poly_eval_orthogonal(jacobi, &c, &x),
+        &poly_eval_orthogonal(OrthogonalBasis::Legendre, &c, &x),
+        1e-14,
+    );
+
+    // alpha = beta = -1/2 is Chebyshev scaled by (2n)! / (2^2n (n!)^2)
+    let jacobi = OrthogonalBasis::Jacobi {
+        alpha: -0.5,
+        beta: -0.5,
+    };
+    let mut scale = 1.0;
+    for n in 0..8 {
+        if n > 0 {
+            scale *= (2 * n - 1) as f64 / (2 * n) as f64;
+        }
+        let mut t = vec![0.0; n + 1];
+        t[n] = scale;
+        assert_close(
+            &orthogonal_polynomial(jacobi, n, &x),
+            &poly_eval_chebyshev(&t, &x, None),
+            1e-14,
+        );
+    }
+
+    // P_1 = ((alpha + beta + 2) x + alpha - beta) / 2
+    let jacobi = OrthogonalBasis::Jacobi {
+        alpha: 1.5,
+        beta: -0.5,
+    };
+    assert_eq!(
+        orthogonal_polynomial(jacobi, 1, &[0.0, 1.0, 2.0]),
+        vec![1.0, 2.5, 4.0]
+    );
+}
+
+#[test]
+fn test_poly_eval_orthogonal_empty() {
+    assert_eq!(
+        poly_eval_orthogonal(OrthogonalBasis::Hermite, &[], &[1.0]),
+        vec![]
+    );
+    let mut result = [1.0; 2];
+    poly_eval_orthogonal_into(OrthogonalBasis::Legendre, &[], &[0.0, 1.0], &mut result);
+    assert_eq!(result, [0.0; 2]);
+    assert_eq!(
+        orthogonal_polynomial(OrthogonalBasis::Laguerre, 0, &[3.0, 5.0]),
+        vec![1.0, 1.0]
+    );
+}
+
+#[test]
+fn test_poly_eval_orthogonal_simd_same_as_scalar() {
+    let c: Vec<f64> = (0..10).map(|i| 0.5 - 0.09 * i as f64).collect();
+    let bases = [
+        OrthogonalBasis::Legendre,
+        OrthogonalBasis::Hermite,
+        OrthogonalBasis::Laguerre,
+        OrthogonalBasis::Jacobi {
+            alpha: 0.3,
+            beta: 1.7,
+        },
+    ];
+    for basis in bases {
+        let a: Vec<f64> = (0..c.len()).map(|k| basis.recurrence(k).0).collect();
+        let b: Vec<f64> = (0..c.len()).map(|k| basis.recurrence(k).1).collect();
+        let g: Vec<f64> = (0..c.len()).map(|k| basis.recurrence(k + 1).2).collect();
+        for n in [7, 8, 9, 16, 27] {
+            let x: Vec<f64> = (0..n).map(|i| 0.09 * i as f64 - 0.8).collect();
+            let mut expected = vec![0.0; n];
+            recurrence_eval(&x, &c, (&a, &b, &g), &mut expected);
+            assert_eq!(poly_eval_orthogonal(basis, &c, &x), expected);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_orthogonal_with_backend() {
+    let c: Vec<f64> = (0..10).map(|i| 0.5 - 0.09 * i as f64).collect();
+    let x: Vec<f64> = (0..27).map(|i| 0.09 * i as f64 - 0.8).collect();
+    let basis = OrthogonalBasis::Jacobi {
+        alpha: -0.5,
+        beta: 0.25,
+    };
+    let expected = poly_eval_orthogonal_with_backend(basis, &c, &x, Backend::Scalar).unwrap();
+    for backend in Backend::ALL {
+        let result = poly_eval_orthogonal_with_backend(basis, &c, &x, backend);
+        if backend.is_supported() {
+            assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+        } else {
+            assert_eq!(result, None);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_jacobi_invalid_parameters() {
+    let basis = OrthogonalBasis::Jacobi {
+        alpha: -1.0,
+        beta: 0.5,
+    };
+    poly_eval_orthogonal(basis, &[1.0, 2.0], &[0.5]);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_recurrence_eval_kernels_same_as_scalar() {
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_recurrence_eval, avx512_recurrence_eval, sse2_recurrence_eval};
+
+    let c: Vec<f64> = (0..11).map(|i| 1.0 / (i as f64 + 1.0) - 0.4).collect();
+    let a: Vec<f64> = (0..11).map(|i| 1.0 + 0.1 * i as f64).collect();
+    let b: Vec<f64> = (0..11).map(|i| 0.05 * i as f64 - 0.2).collect();
+    let g: Vec<f64> = (0..11).map(|i| 0.3 + 0.07 * i as f64).collect();
+    let x: Vec<f64> = (0..16).map(|i| 0.11 * i as f64 - 0.5).collect();
+
+    let mut expected = vec![0.0; x.len()];
+    recurrence_eval(&x, &c, (&a, &b, &g), &mut expected);
+
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_recurrence_eval(&x, &c, (&a, &b, &g), r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_recurrence_eval(&x, &c, (&a, &b, &g), r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_recurrence_eval(&x, &c, (&a, &b, &g), r)
+            }),
+        ],
+    );
+}
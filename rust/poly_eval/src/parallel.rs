@@ -0,0 +1,159 @@
+use std::sync::Mutex;
+use std::thread;
+
+use crate::evaluator::PolynomialEvaluator;
+
+/// 默认的分块大小（自变量个数）。
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+impl PolynomialEvaluator {
+    /**
+    多线程计算多项式的值，并写入调用者提供的缓冲区
+
+    把`x`按`chunk_size`分块，由`threads`个线程分别对各块调用[`eval_into`](Self::eval_into)（SIMD算法）。
+    `chunk_size`会向上取整为8的倍数，因此每个自变量使用的算法与单线程时相同，
+    结果与[`eval_into`](Self::eval_into)逐位相同，与线程数无关。
+
+    # 参数
+
+    - `x`: 自变量的值。
+    - `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。
+    - `chunk_size`: 每块自变量的个数，为0时使用[`DEFAULT_CHUNK_SIZE`]。
+    - `threads`: 线程数，为0时使用机器的可用并行度。
+
+    # 示例
+
+    ```
+    use poly_eval::PolynomialEvaluator;
+    let evaluator = PolynomialEvaluator::new(&[1.0, 2.0, 3.0], None);
+    let x: Vec<f64> = (0..1000).map(|i| i as f64 * 0.001).collect();
+    let mut result = vec![0.0; x.len()];
+    evaluator.par_eval_into(&x, &mut result, 64, 4);
+    assert_eq!(result, evaluator.eval(&x));
+    ```
+    */
+    pub fn par_eval_into(&self, x: &[f64], result: &mut [f64], chunk_size: usize, threads: usize) {
+        assert_eq!(
+            x.len(),
+            result.len(),
+            "result must have the same length as x."
+        );
+        let chunk_size = aligned_chunk_size(chunk_size);
+        let threads = if threads == 0 {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            threads
+        };
+        let threads = threads.min(x.len().div_ceil(chunk_size));
+        if threads <= 1 {
+            self.eval_into(x, result);
+            return;
+        }
+
+        // every thread takes the next chunk until no chunk is left
+        let chunks = Mutex::new(x.chunks(chunk_size).zip(result.chunks_mut(chunk_size)));
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| loop {
+                    let chunk = chunks.lock().unwrap().next();
+                    match chunk {
+                        Some((x, result)) => self.eval_into(x, result),
+                        None => break,
+                    }
+                });
+            }
+        });
+    }
+
+    /**
+    多线程计算多项式的值
+
+    与[`par_eval_into`](Self::par_eval_into)相同，但返回新分配的结果。
+
+    # 参数
+
+    - `x`: 自变量的值。
+    - `chunk_size`: 每块自变量的个数，为0时使用[`DEFAULT_CHUNK_SIZE`]。
+    - `threads`: 线程数，为0时使用机器的可用并行度。
+
+    # 返回值
+
+    计算得到的多项式值，如果多项式没有系数，则返回空的结果。
+    */
+    pub fn par_eval(&self, x: &[f64], chunk_size: usize, threads: usize) -> Vec<f64> {
+        if self.is_empty() {
+            return vec![];
+        }
+        let mut result = vec![0.0; x.len()];
+        self.par_eval_into(x, &mut result, chunk_size, threads);
+        result
+    }
+
+    /**
+    使用rayon的线程池计算多项式的值，并写入调用者提供的缓冲区
+
+    需要开启`rayon`特性。分块规则与[`par_eval_into`](Self::par_eval_into)相同，结果与[`eval_into`](Self::eval_into)逐位相同。
+
+    # 参数
+
+    - `x`: 自变量的值。
+    - `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。
+    - `chunk_size`: 每块自变量的个数，为0时使用[`DEFAULT_CHUNK_SIZE`]。
+    */
+    #[cfg(feature = "rayon")]
+    pub fn rayon_eval_into(&self, x: &[f64], result: &mut [f64], chunk_size: usize) {
+        use rayon::prelude::*;
+
+        assert_eq!(
+            x.len(),
+            result.len(),
+            "result must have the same length as x."
+        );
+        let chunk_size = aligned_chunk_size(chunk_size);
+        x.par_chunks(chunk_size)
+            .zip(result.par_chunks_mut(chunk_size))
+            .for_each(|(x, result)| self.eval_into(x, result));
+    }
+}
+
+/**
+多线程多项式求值
+
+与[`poly_eval`](crate::poly_eval)相同，但把`x`分块后由多个线程分别计算，适用于数百万个自变量的情形，
+结果与[`poly_eval`](crate::poly_eval)逐位相同，与线程数无关，参见[`PolynomialEvaluator::par_eval_into`]。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `chunk_size`: 每块自变量的个数，会向上取整为8的倍数，为0时使用[`DEFAULT_CHUNK_SIZE`]。
+- `threads`: 线程数，为0时使用机器的可用并行度。
+
+# 返回值
+
+计算得到的多项式值。
+*/
+pub fn poly_eval_parallel(
+    c: &[f64],
+    x: &[f64],
+    b: Option<&[f64]>,
+    chunk_size: usize,
+    threads: usize,
+) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    PolynomialEvaluator::new(c, b).par_eval(x, chunk_size, threads)
+}
+
+/// Rounds the chunk size up to a multiple of 8, so that every chunk but the last one is evaluated
+/// by the SIMD kernel entirely, like the 8-aligned prefix of a single-threaded evaluation.
+fn aligned_chunk_size(chunk_size: usize) -> usize {
+    let chunk_size = if chunk_size == 0 {
+        DEFAULT_CHUNK_SIZE
+    } else {
+        chunk_size
+    };
+    chunk_size.next_multiple_of(8)
+}
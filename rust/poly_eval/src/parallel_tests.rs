@@ -0,0 +1,72 @@
+use crate::evaluator::PolynomialEvaluator;
+use crate::parallel::poly_eval_parallel;
+use crate::poly_eval::poly_eval;
+
+fn points(n: usize) -> Vec<f64> {
+    (0..n).map(|i| (i as f64 * 0.37).sin() * 1.5).collect()
+}
+
+#[test]
+fn test_par_eval_same_as_eval() {
+    let c: Vec<f64> = (0..13).map(|i| 1.0 / (i as f64 + 1.0) - 0.3).collect();
+    let b: Vec<f64> = (0..4).map(|i| 0.25 * i as f64).collect();
+    let evaluator = PolynomialEvaluator::new(&c, Some(&b));
+    for n in [0, 5, 8, 100, 1003, 4099] {
+        let x = points(n);
+        let expected = evaluator.eval(&x);
+        for chunk_size in [0, 1, 8, 13, 64, 1000] {
+            for threads in [0, 1, 2, 3, 8] {
+                let mut result = vec![f64::NAN; n];
+                evaluator.par_eval_into(&x, &mut result, chunk_size, threads);
+                assert_eq!(
+                    result, expected,
+                    "n: {}, chunk: {}, threads: {}",
+                    n, chunk_size, threads
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_parallel() {
+    let c = vec![1.0, 2.0, 3.0, 4.0];
+    let x = points(10_001);
+    assert_eq!(
+        poly_eval_parallel(&c, &x, None, 256, 4),
+        poly_eval(&c, &x, None)
+    );
+    assert_eq!(poly_eval_parallel(&[], &x, None, 256, 4), vec![]);
+    assert_eq!(poly_eval_parallel(&c, &[], None, 256, 4), vec![]);
+}
+
+#[test]
+fn test_par_eval_empty_polynomial() {
+    let evaluator = PolynomialEvaluator::new(&[], None);
+    assert_eq!(evaluator.par_eval(&points(100), 8, 4), vec![]);
+    let mut result = vec![1.0; 100];
+    evaluator.par_eval_into(&points(100), &mut result, 8, 4);
+    assert_eq!(result, vec![0.0; 100]);
+}
+
+#[test]
+#[should_panic]
+fn test_par_eval_into_length_mismatch() {
+    let evaluator = PolynomialEvaluator::new(&[1.0], None);
+    let mut result = vec![0.0; 3];
+    evaluator.par_eval_into(&points(4), &mut result, 8, 2);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_rayon_eval_same_as_eval() {
+    let c: Vec<f64> = (0..9).map(|i| 0.5 - i as f64 * 0.1).collect();
+    let evaluator = PolynomialEvaluator::new(&c, None);
+    let x = points(5003);
+    let expected = evaluator.eval(&x);
+    for chunk_size in [0, 7, 64] {
+        let mut result = vec![0.0; x.len()];
+        evaluator.rayon_eval_into(&x, &mut result, chunk_size);
+        assert_eq!(result, expected);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::evaluator::PolynomialEvaluator;
+
 /**
 多项式求值
 
@@ -5,14 +7,16 @@
 多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，其中`c`为多项式系数，`b`为多项式基点（插值点），`x`为自变量.
 如果目标平台支持SIMD指令集（如x86、x86_64、aarch64），并且编译器开启了AVX2、SSE2或NEON特性，
 则使用SIMD加速的算法进行计算；否则，使用普通的算法进行计算。
+需要对同一个多项式反复求值时，使用[`PolynomialEvaluator`](crate::PolynomialEvaluator)可以避免每次调用时重复准备系数；
+需要把结果写入已有的缓冲区时，使用[`poly_eval_into`]。
 
 # 参数
 
-- `c`: 多项式的系数，按照从高到低的顺序排列。
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
 - `x`: 自变量的值。
 - `b`: 可选参数，多项式的基点（插值点）。如果提供了基点（插值点），则使用提供的基点（插值点）进行计算；
-否则，使用默认的基点（插值点）（0.0）进行计算。基点个数必须小于等于多项式系数个数减1，不足的自动以0补充，
-多余多项式系数个数减1则会`panic`。
+  否则，使用默认的基点（插值点）（0.0）进行计算。基点个数必须小于等于多项式系数个数减1，不足的自动以0补充，
+  多余多项式系数个数减1则会`panic`。
 
 # 返回值
 
@@ -28,129 +32,62 @@ let result = poly_eval(&c, &x, None); // 计算多项式的值
 println!("{:?}", result); // 输出计算结果
 ```
 */
-#[allow(dead_code)]
-pub fn poly_eval(c: &Vec<f64>, x: &Vec<f64>, b: Option<&Vec<f64>>) -> Vec<f64> {
-    if c.len() == 0 || x.len() == 0 {
+pub fn poly_eval(c: &[f64], x: &[f64], b: Option<&Vec<f64>>) -> Vec<f64> {
+    if c.is_empty() || x.is_empty() {
         // if no c or no x, return empty
         return vec![];
     }
 
-    if cfg!(all(
-        any(
-            target_arch = "x86",
-            target_arch = "x86_64",
-            target_arch = "aarch64"
-        ),
-        any(
-            target_feature = "avx2",
-            target_feature = "sse2",
-            target_feature = "neon",
-        )
-    )) {
-        if x.len() >= 8 {
-            // use simd only when x length >= 8
-            let remain = x.len() % 8;
-            if remain != 0 {
-                // if x length % 8 != 0, then use no simd for the last few elements
-                let result1 = poly_eval_simd(c, &x[0..x.len() - remain].to_vec(), b);
-                let result2 = poly_eval_no_simd(c, &x[x.len() - remain..].to_vec(), b);
-                return [&result1[..], &result2[..]].concat();
-            } else {
-                return poly_eval_simd(c, x, b);
-            }
-        } else {
-            // if x length < 8, then use no simd
-            return poly_eval_no_simd(c, x, b);
-        }
-    } else {
-        poly_eval_no_simd(c, x, b)
-    }
+    PolynomialEvaluator::new(c, b.map(|b| &b[..])).eval(x)
 }
 
-#[cfg(all(
-    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"),
-    any(
-        target_feature = "avx2",
-        target_feature = "sse2",
-        target_feature = "neon",
-    )
-))]
-#[inline]
-pub(crate) fn poly_eval_simd(c: &Vec<f64>, x: &Vec<f64>, b: Option<&Vec<f64>>) -> Vec<f64> {
-    if c.len() == 0 {
-        // if no c, return empty
-        return vec![];
-    }
-    // reverse c
-    let mut c = c.to_vec();
-    if c.len() % 4 != 0 {
-        // make sure c is 4x aligned
-        c.resize(c.len() + 4 - c.len() % 4, 0.0);
-    }
-    c.reverse();
+/**
+多项式求值（写入调用者提供的缓冲区）
 
-    // if b is not provided, use default
-    let mut base: Vec<f64>;
-    if let Some(b) = b {
-        // if b is provided, use it
-        base = b.to_vec();
-        if base.len() >= c.len() {
-            panic!("total basis numbers must be less than coefficients' numbers-1.");
-        }
-        if base.len() < c.len() - 1 {
-            // base size must equal to c size - 1
-            base.resize(c.len() - 1, 0.0);
-        }
-        // reverse base
-        base.reverse();
-        base.push(0.0);
-    } else {
-        base = vec![0.0; c.len()];
-    }
+与[`poly_eval`]相同，但参数为切片，结果写入调用者提供的`result`，不为结果分配内存。
+每次调用仍需按多项式的次数准备一次系数，反复对同一个多项式求值时，
+使用[`PolynomialEvaluator::eval_into`](crate::PolynomialEvaluator::eval_into)可以完全避免内存分配。
 
-    let mut result: Vec<f64> = vec![0.0; x.len()];
-    #[cfg(target_arch = "aarch64")]
-    {
-        use crate::aarch64_neon::neon_eval;
-        unsafe {
-            neon_eval(x, c, base, &mut result);
-        }
-    }
+# 参数
 
-    #[cfg(target_arch = "x86_64")]
-    {
-        use crate::x86_64::avx2_eval;
-        use crate::x86_64::avx512_eval;
-        use crate::x86_64::sse2_eval;
-        unsafe {
-            if is_x86_feature_detected!("avx512f") {
-                avx512_eval(x, c, base, &mut result);
-            } else if is_x86_feature_detected!("avx2") {
-                avx2_eval(x, c, base, &mut result);
-            } else if is_x86_feature_detected!("sse3") {
-                sse2_eval(x, c, base, &mut result);
-            }
-        }
-    }
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`]相同。
+- `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果`c`为空，结果全部为0。
 
-    #[cfg(target_arch = "x86")]
-    {
-        use crate::x86::sse2_eval;
-        unsafe {
-            if is_x86_feature_detected!("sse3") {
-                sse2_eval(x, c, base, &mut result);
-            } else {
-                ploy_eval_no_simd(x, c, base, &mut result);
-            }
-        }
+# 示例
+
+```
+use poly_eval::poly_eval_into;
+let c = [1.0, 2.0, 3.0];
+let x = [1.0, 2.0, 3.0];
+let mut result = [0.0; 3];
+poly_eval_into(&c, &x, None, &mut result);
+assert_eq!(result, [6.0, 17.0, 34.0]);
+```
+*/
+pub fn poly_eval_into(c: &[f64], x: &[f64], b: Option<&[f64]>, result: &mut [f64]) {
+    PolynomialEvaluator::new(c, b).eval_into(x, result);
+}
+
+#[cfg(test)]
+#[inline]
+pub(crate) fn poly_eval_simd(c: &[f64], x: &[f64], b: Option<&Vec<f64>>) -> Vec<f64> {
+    if c.is_empty() {
+        // if no c, return empty
+        return vec![];
     }
+    let evaluator = PolynomialEvaluator::new(c, b.map(|b| &b[..]));
+    let mut result: Vec<f64> = vec![0.0; x.len()];
+    evaluator.eval_simd(x, &mut result);
     result
 }
 
+#[cfg(test)]
 #[inline]
-pub(crate) fn poly_eval_no_simd(c: &Vec<f64>, x: &Vec<f64>, b: Option<&Vec<f64>>) -> Vec<f64> {
+pub(crate) fn poly_eval_no_simd(c: &[f64], x: &[f64], b: Option<&Vec<f64>>) -> Vec<f64> {
     // if no c, return empty
-    if c.len() == 0 {
+    if c.is_empty() {
         return vec![];
     }
 
@@ -176,8 +113,70 @@ pub(crate) fn poly_eval_no_simd(c: &Vec<f64>, x: &Vec<f64>, b: Option<&Vec<f64>>
             .for_each(|(c_i, b_i)| {
                 y_i = (x_i - b_i) * (c_i + y_i);
             });
-        y_i = y_i + c[0];
+        y_i += c[0];
         result.push(y_i);
     }
     result
 }
+
+/// Horner's method over the reversed layout of [`reverse_coefficients`].
+///
+/// Performs the same operations as the SIMD kernels `sse2_eval`, `avx2_eval` and `neon_eval`.
+#[inline]
+pub(crate) fn horner_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    let last = c.len() - 1;
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let mut y = 0.0;
+        for (c_j, base_j) in c[..last].iter().zip(base) {
+            y = (x_i - base_j) * (c_j + y);
+        }
+        *y_i = y + c[last];
+    }
+}
+
+/// Horner's method with fused multiply-add over the reversed layout of [`reverse_coefficients`],
+/// each step `y = (x - b) * y + c` is rounded only once.
+///
+/// Performs the same operations as the FMA kernels, `x - 0` is exact so the monomial kernels
+/// give the same results as well.
+#[inline]
+pub(crate) fn fma_horner_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let mut y = c[0];
+        for (c_j, base_j) in c[1..].iter().zip(base) {
+            y = (x_i - base_j).mul_add(y, *c_j);
+        }
+        *y_i = y;
+    }
+}
+
+/// Reverses the coefficients `c` and the base points `b` into the layout used by the SIMD kernels.
+///
+/// `c` is padded with zeros to a multiple of `align` before it is reversed, so the reversed `c`
+/// starts with the padding. The base points are padded with zeros to `c.len() - 1`, reversed and
+/// followed by a trailing `0.0`, so `base[j]` belongs to `c[j]` in the reversed layout. It is
+/// generic so that `f32` coefficients share the layout, `T::default()` is the zero.
+pub(crate) fn reverse_coefficients<T: Copy + Default>(
+    c: &[T],
+    b: Option<&[T]>,
+    align: usize,
+) -> (Vec<T>, Vec<T>) {
+    if c.is_empty() {
+        return (vec![], vec![]);
+    }
+    let mut base = b.map_or(vec![], |b| b.to_vec());
+    if !base.is_empty() && base.len() >= c.len() {
+        panic!("total basis numbers must be less than coefficients' numbers-1.");
+    }
+
+    let mut c = c.to_vec();
+    if !c.len().is_multiple_of(align) {
+        c.resize(c.len() + align - c.len() % align, T::default());
+    }
+    c.reverse();
+
+    base.resize(c.len() - 1, T::default());
+    base.reverse();
+    base.push(T::default());
+    (c, base)
+}
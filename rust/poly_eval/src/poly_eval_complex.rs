@@ -0,0 +1,329 @@
+use crate::backend::Backend;
+use crate::complex::Complex64;
+use crate::poly_eval::reverse_coefficients;
+
+/**
+复数多项式求值
+
+计算复数系数多项式在复数自变量处的值，基点（插值点）也可以是复数，可用于复数根的修正、频率响应和围道积分等。
+多项式形如：`c0 + (z-b0)(c1 + (z-b1)(c2 + (z-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同。
+内部把参数转换为实部、虚部分离的布局后调用[`poly_eval_complex_split`]，如果`z`的个数不少于8个则使用SIMD加速的算法。
+系数为实数时，[`poly_eval_real_at_complex`]的计算量更少。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `z`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+计算得到的多项式值。
+
+# 示例
+
+```
+use poly_eval::{poly_eval_complex, Complex64};
+// p(z) = 1 + z^2, p(i) = 0
+let c = [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)];
+let z = [Complex64::new(0.0, 1.0), Complex64::new(1.0, 1.0)];
+let result = poly_eval_complex(&c, &z, None);
+assert_eq!(result, vec![Complex64::new(0.0, 0.0), Complex64::new(1.0, 2.0)]);
+```
+*/
+pub fn poly_eval_complex(
+    c: &[Complex64],
+    z: &[Complex64],
+    b: Option<&[Complex64]>,
+) -> Vec<Complex64> {
+    eval_complex(c, z, b, Backend::selected())
+}
+
+/**
+使用指定的计算后端进行复数多项式求值
+
+与[`poly_eval_complex`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `z`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+计算得到的多项式值，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_complex_with_backend(
+    c: &[Complex64],
+    z: &[Complex64],
+    b: Option<&[Complex64]>,
+    backend: Backend,
+) -> Option<Vec<Complex64>> {
+    backend
+        .is_supported()
+        .then(|| eval_complex(c, z, b, backend))
+}
+
+fn eval_complex(
+    c: &[Complex64],
+    z: &[Complex64],
+    b: Option<&[Complex64]>,
+    backend: Backend,
+) -> Vec<Complex64> {
+    if c.is_empty() || z.is_empty() {
+        return vec![];
+    }
+
+    let (c_re, c_im) = split(c);
+    let (z_re, z_im) = split(z);
+    let b = b.map(split);
+    let mut re = vec![0.0; z.len()];
+    let mut im = vec![0.0; z.len()];
+    eval_complex_split(
+        (&c_re, &c_im),
+        (&z_re, &z_im),
+        b.as_ref().map(|(re, im)| (&re[..], &im[..])),
+        backend,
+        (&mut re, &mut im),
+    );
+    re.into_iter()
+        .zip(im)
+        .map(|(re, im)| Complex64::new(re, im))
+        .collect()
+}
+
+/**
+复数多项式求值（实部、虚部分离的布局）
+
+与[`poly_eval_complex`]相同，但系数、自变量、基点和结果都以实部数组和虚部数组分别存放，
+这种布局适合SIMD计算，也避免了转换的开销。
+如果`z`的个数不少于8个，则对8的整数倍部分使用[`Backend::selected`](crate::Backend::selected)的SIMD内核
+（`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON），剩余部分使用普通的算法。
+
+# 参数
+
+- `c`: 多项式系数的`(实部, 虚部)`，两者长度必须相同，`c[0]`为常数项。
+- `z`: 自变量的`(实部, 虚部)`，两者长度必须相同。
+- `b`: 可选参数，多项式基点（插值点）的`(实部, 虚部)`，两者长度必须相同，规则与[`poly_eval`](crate::poly_eval)相同。
+- `result`: 存放结果的`(实部, 虚部)`缓冲区，长度必须与`z`相同，否则会`panic`。如果`c`为空，结果全部为0。
+
+# 示例
+
+```
+use poly_eval::poly_eval_complex_split;
+// p(z) = 1 + z^2
+let (c_re, c_im) = ([1.0, 0.0, 1.0], [0.0; 3]);
+let (z_re, z_im) = ([0.0, 1.0], [1.0, 1.0]);
+let (mut re, mut im) = ([0.0; 2], [0.0; 2]);
+poly_eval_complex_split((&c_re, &c_im), (&z_re, &z_im), None, (&mut re, &mut im));
+assert_eq!((re, im), ([0.0, 1.0], [0.0, 2.0]));
+```
+*/
+pub fn poly_eval_complex_split(
+    c: (&[f64], &[f64]),
+    z: (&[f64], &[f64]),
+    b: Option<(&[f64], &[f64])>,
+    result: (&mut [f64], &mut [f64]),
+) {
+    eval_complex_split(c, z, b, Backend::selected(), result);
+}
+
+fn eval_complex_split(
+    c: (&[f64], &[f64]),
+    z: (&[f64], &[f64]),
+    b: Option<(&[f64], &[f64])>,
+    backend: Backend,
+    result: (&mut [f64], &mut [f64]),
+) {
+    assert_eq!(
+        c.0.len(),
+        c.1.len(),
+        "real and imaginary parts of c must have the same length."
+    );
+    assert_eq!(
+        z.0.len(),
+        z.1.len(),
+        "real and imaginary parts of z must have the same length."
+    );
+    if let Some(b) = b {
+        assert_eq!(
+            b.0.len(),
+            b.1.len(),
+            "real and imaginary parts of b must have the same length."
+        );
+    }
+    assert!(
+        result.0.len() == z.0.len() && result.1.len() == z.0.len(),
+        "result must have the same length as z."
+    );
+    let (re, im) = result;
+    if c.0.is_empty() {
+        re.fill(0.0);
+        im.fill(0.0);
+        return;
+    }
+
+    let (c, base) = split_coefficients(c, b);
+    let c = (&c.0[..], &c.1[..]);
+    let base = (&base.0[..], &base.1[..]);
+    let aligned = if z.0.len() >= 8 && backend != Backend::Scalar {
+        // use simd only when z length >= 8, and no simd for the last few elements
+        z.0.len() - z.0.len() % 8
+    } else {
+        0
+    };
+    let (re_head, re_tail) = re.split_at_mut(aligned);
+    let (im_head, im_tail) = im.split_at_mut(aligned);
+    complex_eval_simd(
+        (&z.0[..aligned], &z.1[..aligned]),
+        c,
+        base,
+        backend,
+        (re_head, im_head),
+    );
+    complex_eval(
+        (&z.0[aligned..], &z.1[aligned..]),
+        c,
+        base,
+        (re_tail, im_tail),
+    );
+}
+
+/**
+实系数多项式在复数自变量处求值（Goertzel算法）
+
+实系数多项式满足`p(conj(z)) = conj(p(z))`，因此可以把`p(x)`除以以`z`和`conj(z)`为根的实二次式
+`x^2 - 2Re(z)x + |z|^2`，得到余式`αx + β`，则`p(z) = αz + β`。
+整个递推只使用实数运算，每一步约4次实数运算，而复数Horner算法每一步约8次，
+代价是在`|z|`较大时精度略低于复数Horner算法。只支持单项式形式（没有基点）。
+
+# 参数
+
+- `c`: 多项式的实系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `z`: 自变量的值。
+
+# 返回值
+
+计算得到的多项式值，`conj(z)`处的值为其共轭。
+
+# 示例
+
+```
+use poly_eval::{poly_eval_real_at_complex, Complex64};
+// p(z) = 1 + z^2, p(i) = 0
+let result = poly_eval_real_at_complex(&[1.0, 0.0, 1.0], &[Complex64::new(0.0, 1.0)]);
+assert_eq!(result, vec![Complex64::new(0.0, 0.0)]);
+```
+*/
+pub fn poly_eval_real_at_complex(c: &[f64], z: &[Complex64]) -> Vec<Complex64> {
+    if c.is_empty() {
+        return vec![];
+    }
+    z.iter()
+        .map(|z| {
+            let r = 2.0 * z.re;
+            let s = z.re * z.re + z.im * z.im;
+            // b_k = c_k + r b_{k+1} - s b_{k+2}, from the highest k down to 1
+            let (mut b_1, mut b_2) = (0.0, 0.0);
+            for c_k in c[1..].iter().rev() {
+                (b_1, b_2) = (c_k + r * b_1 - s * b_2, b_1);
+            }
+            // the remainder is b_1 x + (c_0 - s b_2)
+            let beta = c[0] - s * b_2;
+            Complex64::new(b_1 * z.re + beta, b_1 * z.im)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) fn poly_eval_complex_no_simd(
+    c: &[Complex64],
+    z: &[Complex64],
+    b: Option<&[Complex64]>,
+) -> Vec<Complex64> {
+    eval_complex(c, z, b, Backend::Scalar)
+}
+
+type Split = (Vec<f64>, Vec<f64>);
+
+/// Splits complex numbers into real and imaginary parts.
+fn split(v: &[Complex64]) -> Split {
+    v.iter().map(|v| (v.re, v.im)).unzip()
+}
+
+/// Joins real and imaginary parts of the same length into complex numbers.
+fn join(v: (&[f64], &[f64])) -> Vec<Complex64> {
+    v.0.iter()
+        .zip(v.1)
+        .map(|(re, im)| Complex64::new(*re, *im))
+        .collect()
+}
+
+/// Reverses the coefficients and the base points like [`reverse_coefficients`], and splits them
+/// into real and imaginary parts.
+pub(crate) fn split_coefficients(
+    c: (&[f64], &[f64]),
+    b: Option<(&[f64], &[f64])>,
+) -> (Split, Split) {
+    let b = b.map(join);
+    let (c, base) = reverse_coefficients(&join(c), b.as_deref(), 1);
+    (split(&c), split(&base))
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `z` must be a multiple
+/// of 8.
+fn complex_eval_simd(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    backend: Backend,
+    result: (&mut [f64], &mut [f64]),
+) {
+    if z.0.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_complex_eval(z, c, base, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_complex_eval(z, c, base, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_complex_eval(z, c, base, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_complex_eval(z, c, base, result)
+        },
+        _ => complex_eval(z, c, base, result),
+    }
+}
+
+/// Complex Horner's method over the split reversed layout of [`split_coefficients`], the scalar
+/// reference of the `*_complex_eval` kernels.
+pub(crate) fn complex_eval(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    result: (&mut [f64], &mut [f64]),
+) {
+    for i in 0..z.0.len() {
+        let (mut s_re, mut s_im) = (c.0[0], c.1[0]);
+        for j in 1..c.0.len() {
+            // s = (z - b) * s + c
+            let t_re = z.0[i] - base.0[j - 1];
+            let t_im = z.1[i] - base.1[j - 1];
+            let p_re = t_re * s_re - t_im * s_im;
+            let p_im = t_re * s_im + t_im * s_re;
+            s_re = p_re + c.0[j];
+            s_im = p_im + c.1[j];
+        }
+        result.0[i] = s_re;
+        result.1[i] = s_im;
+    }
+}
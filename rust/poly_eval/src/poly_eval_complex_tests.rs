@@ -0,0 +1,219 @@
+use crate::backend::Backend;
+use crate::complex::Complex64;
+use crate::poly_eval_complex::{
+    poly_eval_complex, poly_eval_complex_no_simd, poly_eval_complex_split,
+    poly_eval_complex_with_backend, poly_eval_real_at_complex,
+};
+
+fn coefficients() -> Vec<Complex64> {
+    (0..9)
+        .map(|i| Complex64::new(1.0 / (i as f64 + 1.0) - 0.4, 0.1 * i as f64 - 0.3))
+        .collect()
+}
+
+fn points(n: usize) -> Vec<Complex64> {
+    (0..n)
+        .map(|i| Complex64::new(0.07 * i as f64 - 0.9, 0.5 - 0.03 * i as f64))
+        .collect()
+}
+
+/// Direct evaluation of the Newton form by the complex operators.
+fn newton_form(c: &[Complex64], z: Complex64, b: &[Complex64]) -> Complex64 {
+    let mut y = Complex64::default();
+    let mut product = Complex64::new(1.0, 0.0);
+    for (j, c_j) in c.iter().enumerate() {
+        y = y + *c_j * product;
+        product = product * (z - b.get(j).copied().unwrap_or_default());
+    }
+    y
+}
+
+fn assert_close(result: &[Complex64], expected: &[Complex64]) {
+    assert_eq!(result.len(), expected.len());
+    for (r, e) in result.iter().zip(expected) {
+        assert!(
+            (*r - *e).norm() <= 1e-14 * e.norm().max(1.0),
+            "{:?} {:?}",
+            r,
+            e
+        );
+    }
+}
+
+#[test]
+fn test_poly_eval_complex_roots_of_unity() {
+    // z^4 - 1 vanishes at 1, i, -1, -i
+    let c = [-1.0, 0.0, 0.0, 0.0, 1.0].map(Complex64::from);
+    let z = [
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 1.0),
+        Complex64::new(-1.0, 0.0),
+        Complex64::new(0.0, -1.0),
+    ];
+    assert_eq!(
+        poly_eval_complex(&c, &z, None),
+        vec![Complex64::default(); 4]
+    );
+}
+
+#[test]
+fn test_poly_eval_complex_newton_form() {
+    let c = coefficients();
+    let b: Vec<Complex64> = (0..5)
+        .map(|i| Complex64::new(0.2 * i as f64, -0.1 * i as f64))
+        .collect();
+    let z = points(21);
+    let expected: Vec<Complex64> = z.iter().map(|z| newton_form(&c, *z, &b)).collect();
+    assert_close(&poly_eval_complex(&c, &z, Some(&b)), &expected);
+}
+
+#[test]
+fn test_poly_eval_complex_simd_same_as_no_simd() {
+    let c = coefficients();
+    let b = [Complex64::new(0.5, 0.25), Complex64::new(-0.5, 1.0)];
+    for n in [0, 1, 7, 8, 9, 16, 23] {
+        let z = points(n);
+        let expected = poly_eval_complex_no_simd(&c, &z, Some(&b));
+        assert_eq!(poly_eval_complex(&c, &z, Some(&b)), expected, "n: {}", n);
+    }
+}
+
+#[test]
+fn test_poly_eval_complex_with_backend() {
+    let c = coefficients();
+    let b = [Complex64::new(0.5, 0.25), Complex64::new(-0.5, 1.0)];
+    let z = points(23);
+    let expected = poly_eval_complex_no_simd(&c, &z, Some(&b));
+    for backend in Backend::ALL {
+        let result = poly_eval_complex_with_backend(&c, &z, Some(&b), backend);
+        if backend.is_supported() {
+            assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+        } else {
+            assert_eq!(result, None);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_complex_split() {
+    let c = coefficients();
+    let b = [Complex64::new(0.3, -0.2), Complex64::new(0.1, 0.4)];
+    let z = points(19);
+    let expected = poly_eval_complex(&c, &z, Some(&b));
+
+    let c_re: Vec<f64> = c.iter().map(|c| c.re).collect();
+    let c_im: Vec<f64> = c.iter().map(|c| c.im).collect();
+    let z_re: Vec<f64> = z.iter().map(|z| z.re).collect();
+    let z_im: Vec<f64> = z.iter().map(|z| z.im).collect();
+    let b_re: Vec<f64> = b.iter().map(|b| b.re).collect();
+    let b_im: Vec<f64> = b.iter().map(|b| b.im).collect();
+    let mut re = vec![0.0; z.len()];
+    let mut im = vec![0.0; z.len()];
+    poly_eval_complex_split(
+        (&c_re, &c_im),
+        (&z_re, &z_im),
+        Some((&b_re, &b_im)),
+        (&mut re, &mut im),
+    );
+    let result: Vec<Complex64> = re
+        .into_iter()
+        .zip(im)
+        .map(|(re, im)| Complex64::new(re, im))
+        .collect();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_poly_eval_real_at_complex() {
+    let c = [0.5, -1.25, 2.0, 0.75, -3.0, 1.5, 0.125];
+    let c_complex = c.map(Complex64::from);
+    let z = points(17);
+    let expected = poly_eval_complex(&c_complex, &z, None);
+    let result = poly_eval_real_at_complex(&c, &z);
+    assert_close(&result, &expected);
+
+    // p(conj(z)) = conj(p(z))
+    let conj: Vec<Complex64> = z.iter().map(|z| z.conj()).collect();
+    let result_conj = poly_eval_real_at_complex(&c, &conj);
+    assert_eq!(
+        result_conj,
+        result.iter().map(|r| r.conj()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_poly_eval_real_at_complex_low_degree() {
+    let z = [Complex64::new(2.0, 3.0)];
+    assert_eq!(
+        poly_eval_real_at_complex(&[4.0], &z),
+        vec![Complex64::new(4.0, 0.0)]
+    );
+    assert_eq!(
+        poly_eval_real_at_complex(&[4.0, 2.0], &z),
+        vec![Complex64::new(8.0, 6.0)]
+    );
+    assert_eq!(poly_eval_real_at_complex(&[], &z), vec![]);
+}
+
+#[test]
+fn test_poly_eval_complex_empty() {
+    let z = points(3);
+    assert_eq!(poly_eval_complex(&[], &z, None), vec![]);
+    assert_eq!(poly_eval_complex(&coefficients(), &[], None), vec![]);
+    let mut re = [1.0; 3];
+    let mut im = [1.0; 3];
+    poly_eval_complex_split((&[], &[]), (&[0.0; 3], &[0.0; 3]), None, (&mut re, &mut im));
+    assert_eq!((re, im), ([0.0; 3], [0.0; 3]));
+}
+
+#[test]
+#[should_panic]
+fn test_poly_eval_complex_split_length_mismatch() {
+    let mut re = [0.0; 2];
+    let mut im = [0.0; 2];
+    poly_eval_complex_split(
+        (&[1.0, 2.0], &[1.0]),
+        (&[0.0; 2], &[0.0; 2]),
+        None,
+        (&mut re, &mut im),
+    );
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_complex_eval_kernels_same_as_scalar() {
+    use crate::poly_eval_complex::{complex_eval, split_coefficients};
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_complex_eval, avx512_complex_eval, sse2_complex_eval};
+
+    let c = coefficients();
+    let c_re: Vec<f64> = c.iter().map(|c| c.re).collect();
+    let c_im: Vec<f64> = c.iter().map(|c| c.im).collect();
+    let (b_re, b_im) = ([0.3, 0.1], [-0.2, 0.4]);
+    let (c, base) = split_coefficients((&c_re, &c_im), Some((&b_re, &b_im)));
+    let c = (&c.0[..], &c.1[..]);
+    let base = (&base.0[..], &base.1[..]);
+    let z = points(16);
+    let z_re: Vec<f64> = z.iter().map(|z| z.re).collect();
+    let z_im: Vec<f64> = z.iter().map(|z| z.im).collect();
+    let z = (&z_re[..], &z_im[..]);
+
+    let mut expected = vec![0.0; 32];
+    let (re, im) = expected.split_at_mut(16);
+    complex_eval(z, c, base, (re, im));
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_complex_eval(z, c, base, r.split_at_mut(16))
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_complex_eval(z, c, base, r.split_at_mut(16))
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_complex_eval(z, c, base, r.split_at_mut(16))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,159 @@
+use crate::backend::Backend;
+use crate::poly_eval::reverse_coefficients;
+
+/**
+单精度（`f32`）多项式求值
+
+与[`poly_eval`](crate::poly_eval)相同，但系数、基点和自变量均为`f32`，适用于信号处理等只需要单精度的多项式近似。
+多项式形如：`c0 + (x-b0)(c1 + (x-b1)(c2 + (x-b2)(c3 + ...)))`，参数约定与[`poly_eval`](crate::poly_eval)相同。
+如果`x`的个数不少于16个，则对16的整数倍部分使用[`Backend::selected`](crate::Backend::selected)的SIMD内核
+（`x86_64`的AVX512F（16路）、AVX2（8路）或SSE（4路），`aarch64`的NEON（4路）），剩余部分使用普通的算法。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+
+# 返回值
+
+计算得到的多项式值。
+
+# 示例
+
+```
+use poly_eval::poly_eval_f32;
+let c = [1.0f32, 2.0, 3.0];
+let x = [1.0f32, 2.0, 3.0];
+let result = poly_eval_f32(&c, &x, None);
+assert_eq!(result, vec![6.0, 17.0, 34.0]);
+```
+*/
+pub fn poly_eval_f32(c: &[f32], x: &[f32], b: Option<&[f32]>) -> Vec<f32> {
+    if c.is_empty() || x.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0.0; x.len()];
+    poly_eval_f32_into(c, x, b, &mut result);
+    result
+}
+
+/**
+单精度（`f32`）多项式求值（写入调用者提供的缓冲区）
+
+与[`poly_eval_f32`]相同，但结果写入调用者提供的`result`，不为结果分配内存。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。如果`c`为空，结果全部为0。
+*/
+pub fn poly_eval_f32_into(c: &[f32], x: &[f32], b: Option<&[f32]>, result: &mut [f32]) {
+    eval_f32_into(c, x, b, Backend::selected(), result);
+}
+
+/**
+使用指定的计算后端进行单精度（`f32`）多项式求值
+
+与[`poly_eval_f32`]相同，但强制使用`backend`，参见[`Backend`](crate::Backend)。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的值。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `backend`: 强制使用的计算后端。
+
+# 返回值
+
+计算得到的多项式值，当前CPU不支持`backend`时返回`None`。
+*/
+pub fn poly_eval_f32_with_backend(
+    c: &[f32],
+    x: &[f32],
+    b: Option<&[f32]>,
+    backend: Backend,
+) -> Option<Vec<f32>> {
+    if !backend.is_supported() {
+        return None;
+    }
+    if c.is_empty() || x.is_empty() {
+        return Some(vec![]);
+    }
+    let mut result = vec![0.0; x.len()];
+    eval_f32_into(c, x, b, backend, &mut result);
+    Some(result)
+}
+
+fn eval_f32_into(c: &[f32], x: &[f32], b: Option<&[f32]>, backend: Backend, result: &mut [f32]) {
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "result must have the same length as x."
+    );
+    if c.is_empty() {
+        result.fill(0.0);
+        return;
+    }
+
+    let (c, base) = reverse_coefficients(c, b, 1);
+    let aligned = if x.len() >= 16 && backend != Backend::Scalar {
+        // use simd only when x length >= 16, and no simd for the last few elements
+        x.len() - x.len() % 16
+    } else {
+        0
+    };
+    eval_f32_simd(&x[..aligned], &c, &base, backend, &mut result[..aligned]);
+    horner_eval_f32(&x[aligned..], &c, &base, &mut result[aligned..]);
+}
+
+#[cfg(test)]
+pub(crate) fn poly_eval_f32_no_simd(c: &[f32], x: &[f32], b: Option<&[f32]>) -> Vec<f32> {
+    if c.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0.0; x.len()];
+    eval_f32_into(c, x, b, Backend::Scalar, &mut result);
+    result
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 16.
+fn eval_f32_simd(x: &[f32], c: &[f32], base: &[f32], backend: Backend, result: &mut [f32]) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse_eval_f32(x, c, base, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_eval_f32(x, c, base, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_eval_f32(x, c, base, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_eval_f32(x, c, base, result)
+        },
+        _ => horner_eval_f32(x, c, base, result),
+    }
+}
+
+/// Horner's method in `f32` over the reversed layout of [`reverse_coefficients`], the scalar
+/// reference of the `*_eval_f32` kernels.
+#[inline]
+pub(crate) fn horner_eval_f32(x: &[f32], c: &[f32], base: &[f32], result: &mut [f32]) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let mut y = c[0];
+        for (c_j, base_j) in c[1..].iter().zip(base) {
+            y = (x_i - base_j) * y + c_j;
+        }
+        *y_i = y;
+    }
+}
@@ -0,0 +1,111 @@
+use crate::backend::Backend;
+use crate::poly_eval::poly_eval_no_simd;
+use crate::poly_eval_f32::{
+    poly_eval_f32, poly_eval_f32_into, poly_eval_f32_no_simd, poly_eval_f32_with_backend,
+};
+
+#[test]
+fn test_poly_eval_f32_16x() {
+    let c = [1.0, 2.0, 3.0, 4.0];
+    let x: Vec<f32> = (1..=16).map(|i| i as f32).collect();
+    let expected = vec![
+        10.0, 49.0, 142.0, 313.0, 586.0, 985.0, 1534.0, 2257.0, 3178.0, 4321.0, 5710.0, 7369.0,
+        9322.0, 11593.0, 14206.0, 17185.0,
+    ];
+    assert_eq!(poly_eval_f32_no_simd(&c, &x, None), expected);
+    assert_eq!(poly_eval_f32(&c, &x, None), expected);
+}
+
+#[test]
+fn test_poly_eval_f32_with_backend() {
+    let c: Vec<f32> = (0..9).map(|i| 1.0 / (i as f32 + 1.0) - 0.4).collect();
+    let b: Vec<f32> = (0..5).map(|i| 0.3 * i as f32).collect();
+    let x: Vec<f32> = (0..37).map(|i| 0.05 * i as f32 - 0.8).collect();
+    let expected = poly_eval_f32_no_simd(&c, &x, Some(&b));
+    for backend in Backend::ALL {
+        let result = poly_eval_f32_with_backend(&c, &x, Some(&b), backend);
+        if backend.is_supported() {
+            assert_eq!(result.as_ref(), Some(&expected), "backend: {:?}", backend);
+        } else {
+            assert_eq!(result, None);
+        }
+    }
+}
+
+#[test]
+fn test_poly_eval_f32_with_base() {
+    let c = [1.0, 2.0, 3.0, 4.0];
+    let b = [3.0, 4.0, 5.0];
+    let x: Vec<f32> = (3..=20).map(|i| i as f32).collect();
+    let expected: Vec<f32> = poly_eval_no_simd(
+        &[1.0, 2.0, 3.0, 4.0],
+        &x.iter().map(|x| *x as f64).collect::<Vec<_>>(),
+        Some(&vec![3.0, 4.0, 5.0]),
+    )
+    .iter()
+    .map(|y| *y as f32)
+    .collect();
+    assert_eq!(poly_eval_f32(&c, &x, Some(&b)), expected);
+}
+
+#[test]
+fn test_poly_eval_f32_simd_same_as_no_simd() {
+    let c: Vec<f32> = (0..9).map(|i| 1.0 / (i as f32 + 1.0) - 0.4).collect();
+    let b: Vec<f32> = (0..5).map(|i| 0.3 * i as f32).collect();
+    for n in [0, 1, 15, 16, 17, 33, 64] {
+        let x: Vec<f32> = (0..n).map(|i| 0.05 * i as f32 - 0.8).collect();
+        let expected = poly_eval_f32_no_simd(&c, &x, Some(&b));
+        assert_eq!(poly_eval_f32(&c, &x, Some(&b)), expected, "n: {}", n);
+        let mut result = vec![1.0; n];
+        poly_eval_f32_into(&c, &x, Some(&b), &mut result);
+        assert_eq!(result, expected, "n: {}", n);
+    }
+}
+
+#[test]
+fn test_poly_eval_f32_empty() {
+    assert_eq!(poly_eval_f32(&[], &[1.0, 2.0], None), Vec::<f32>::new());
+    assert_eq!(poly_eval_f32(&[1.0], &[], None), Vec::<f32>::new());
+    let mut result = [1.0; 2];
+    poly_eval_f32_into(&[], &[1.0, 2.0], None, &mut result);
+    assert_eq!(result, [0.0; 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_eval_f32_too_many_base() {
+    poly_eval_f32(&[1.0, 2.0], &[1.0], Some(&[1.0, 2.0]));
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_eval_f32_kernels_same_as_scalar() {
+    use crate::poly_eval::reverse_coefficients;
+    use crate::poly_eval_f32::horner_eval_f32;
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_eval_f32, avx512_eval_f32, sse_eval_f32};
+
+    let c: Vec<f32> = (0..11).map(|i| 1.0 / (i as f32 + 1.0) - 0.4).collect();
+    let b: Vec<f32> = (0..7).map(|i| 0.3 * i as f32).collect();
+    let x: Vec<f32> = (0..32).map(|i| 0.07 * i as f32 - 0.5).collect();
+    let (c, base) = reverse_coefficients(&c, Some(&b), 1);
+
+    let mut expected = vec![0.0; x.len()];
+    horner_eval_f32(&x, &c, &base, &mut expected);
+
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse_eval_f32(&x, &c, &base, r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_eval_f32(&x, &c, &base, r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_eval_f32(&x, &c, &base, r)
+            }),
+        ],
+    );
+}
@@ -0,0 +1,190 @@
+use crate::interval::Interval;
+use crate::newton_form::padded_base;
+
+/**
+多项式值域的包围方法
+
+- `Horner`: 朴素的区间Horner算法，直接对牛顿形式做区间运算，最快，但由于相关性问题通常偏宽。
+- `MeanValue`: 中值形式（中心形式）`p(m) + p'(X)(X - m)`，`m`为区间中点，区间越窄越接近真实值域（二次收敛）。
+- `Bernstein`: 把多项式转换为区间`[lo, hi]`上的伯恩斯坦系数，系数的最小值和最大值即为包围，
+  通常最紧，计算量为`O(n^2)`。
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnclosureMethod {
+    Horner,
+    MeanValue,
+    Bernstein,
+}
+
+/**
+多项式在区间上的值域包围
+
+返回一个区间，保证包含多项式在`x`上的所有取值。所有计算（包括牛顿形式到单项式、伯恩斯坦系数的转换）都使用
+向外舍入的区间运算，所以结果对舍入误差也是可靠的，可以用于排除根或者安全检查。
+
+# 参数
+
+- `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项。
+- `x`: 自变量的区间。
+- `b`: 可选参数，多项式的基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同。
+- `method`: 包围方法，参见[`EnclosureMethod`]。
+
+# 返回值
+
+包含多项式值域的区间，`c`为空时返回`[0, 0]`。
+
+# 示例
+
+```
+use poly_eval::{poly_eval_interval, EnclosureMethod, Interval};
+// x^2 - x has the range [-0.25, 0] on [0, 1]
+let c = [0.0, -1.0, 1.0];
+let x = Interval::new(0.0, 1.0);
+let horner = poly_eval_interval(&c, x, None, EnclosureMethod::Horner);
+let bernstein = poly_eval_interval(&c, x, None, EnclosureMethod::Bernstein);
+assert!(horner.lo <= -1.0 && horner.hi >= 0.0);
+assert!(bernstein.lo <= -0.5 && bernstein.lo > -0.51 && bernstein.hi >= 0.0);
+```
+*/
+pub fn poly_eval_interval(
+    c: &[f64],
+    x: Interval,
+    b: Option<&[f64]>,
+    method: EnclosureMethod,
+) -> Interval {
+    if c.is_empty() {
+        return Interval::point(0.0);
+    }
+    let base = padded_base(c, b.unwrap_or(&[]));
+    match method {
+        EnclosureMethod::Horner => interval_horner(c, &base, x),
+        EnclosureMethod::MeanValue => mean_value_form(c, &base, x),
+        EnclosureMethod::Bernstein => bernstein_bound(c, &base, x),
+    }
+}
+
+/// Interval Horner's method for the Newton form `c0 + (x-b0)(c1 + (x-b1)(c2 + ...))`.
+fn interval_horner(c: &[f64], base: &[f64], x: Interval) -> Interval {
+    let last = c.len() - 1;
+    base.iter()
+        .zip(&c[..last])
+        .rev()
+        .fold(Interval::point(c[last]), |y, (b_j, c_j)| {
+            (x - Interval::point(*b_j)) * y + Interval::point(*c_j)
+        })
+}
+
+/// `p(m) + p'(X)(X - m)` with `m` the midpoint of `X`.
+fn mean_value_form(c: &[f64], base: &[f64], x: Interval) -> Interval {
+    let m = Interval::point(x.midpoint());
+    let p_m = interval_horner(c, base, m);
+
+    let a = interval_monomial(c, base);
+    let derivative = a[1..]
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(Interval::point(0.0), |y, (k, a_k)| {
+            y * x + *a_k * Interval::point((k + 1) as f64)
+        });
+    p_m + derivative * (x - m)
+}
+
+/// The hull of the Bernstein coefficients of `p` on `X`.
+fn bernstein_bound(c: &[f64], base: &[f64], x: Interval) -> Interval {
+    let a = interval_monomial(c, base);
+    let n = a.len() - 1;
+
+    // substitute x = lo + w t, t in [0, 1], w encloses hi - lo
+    let lo = Interval::point(x.lo);
+    let w = Interval::point(x.hi) - lo;
+    let mut shifted = vec![Interval::point(0.0); a.len()];
+    for (degree, a_j) in a.iter().enumerate().rev() {
+        // shifted = shifted * (lo + w t) + a_j over the current degree n - degree
+        for k in (0..=n - degree).rev() {
+            let lower = if k > 0 {
+                shifted[k - 1] * w
+            } else {
+                Interval::point(0.0)
+            };
+            shifted[k] = shifted[k] * lo + lower;
+        }
+        shifted[0] = shifted[0] + *a_j;
+    }
+
+    // b_k = sum_{j <= k} C(k, j) / C(n, j) a_j
+    let pascal = pascal_triangle(n);
+    let scaled: Vec<Interval> = shifted
+        .iter()
+        .zip(&pascal[n])
+        .map(|(a_j, binomial)| *a_j / *binomial)
+        .collect();
+    let mut hull: Option<Interval> = None;
+    for row in &pascal {
+        let b_k = row
+            .iter()
+            .zip(&scaled)
+            .fold(Interval::point(0.0), |s, (binomial, a_j)| {
+                s + *binomial * *a_j
+            });
+        hull = Some(match hull {
+            Some(h) => Interval {
+                lo: h.lo.min(b_k.lo),
+                hi: h.hi.max(b_k.hi),
+            },
+            None => b_k,
+        });
+    }
+    hull.unwrap_or(Interval::point(0.0))
+}
+
+/// Expands the Newton form into monomial coefficients with interval arithmetic.
+fn interval_monomial(c: &[f64], base: &[f64]) -> Vec<Interval> {
+    let last = c.len() - 1;
+    let mut a = vec![Interval::point(0.0); c.len()];
+    a[0] = Interval::point(c[last]);
+    for (degree, (b_j, c_j)) in base.iter().zip(&c[..last]).rev().enumerate() {
+        // a = a * (x - b_j) + c_j, a has degree `degree`
+        let b_j = Interval::point(*b_j);
+        for k in (0..=degree + 1).rev() {
+            let shifted = if k > 0 {
+                a[k - 1]
+            } else {
+                Interval::point(0.0)
+            };
+            let own = if k <= degree {
+                a[k] * b_j
+            } else {
+                Interval::point(0.0)
+            };
+            a[k] = shifted - own;
+        }
+        a[0] = a[0] + Interval::point(*c_j);
+    }
+    a
+}
+
+/// Rows `0..=n` of Pascal's triangle as enclosures of the binomial coefficients.
+///
+/// Sums below 2^53 are exact, so they stay points, larger ones (from `C(57, 28)` on) are rounded
+/// outward by the interval addition.
+pub(crate) fn pascal_triangle(n: usize) -> Vec<Vec<Interval>> {
+    // integers below 2^53 are exact in f64
+    const EXACT: f64 = 9007199254740992.0;
+    let one = Interval::point(1.0);
+    let mut rows: Vec<Vec<Interval>> = vec![vec![one]];
+    for k in 1..=n {
+        let prev = &rows[k - 1];
+        let mut row = vec![one; k + 1];
+        for j in 1..k {
+            let (l, r) = (prev[j - 1], prev[j]);
+            row[j] = if l.lo == l.hi && r.lo == r.hi && l.lo + r.lo < EXACT {
+                Interval::point(l.lo + r.lo)
+            } else {
+                l + r
+            };
+        }
+        rows.push(row);
+    }
+    rows
+}
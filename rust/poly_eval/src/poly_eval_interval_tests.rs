@@ -0,0 +1,181 @@
+use crate::interval::Interval;
+use crate::poly_eval::poly_eval;
+use crate::poly_eval_interval::{pascal_triangle, poly_eval_interval, EnclosureMethod};
+
+const METHODS: [EnclosureMethod; 3] = [
+    EnclosureMethod::Horner,
+    EnclosureMethod::MeanValue,
+    EnclosureMethod::Bernstein,
+];
+
+fn assert_encloses(c: &[f64], x: Interval, b: Option<&Vec<f64>>) {
+    let points: Vec<f64> = (0..=200)
+        .map(|i| x.lo + (x.hi - x.lo) * i as f64 / 200.0)
+        .collect();
+    let values = poly_eval(c, &points, b);
+    for method in METHODS {
+        let range = poly_eval_interval(c, x, b.map(|b| &b[..]), method);
+        for (v, p) in values.iter().zip(&points) {
+            assert!(
+                range.contains(*v),
+                "{:?}: {} at {} not in {:?}",
+                method,
+                v,
+                p,
+                range
+            );
+        }
+    }
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let x = Interval::point(0.1) + Interval::point(0.2);
+    assert!(x.lo < x.hi && x.contains(0.1 + 0.2));
+    assert!(x.width() <= 4.0 * f64::EPSILON);
+
+    let x = Interval::new(-1.0, 2.0);
+    let y = x * x;
+    assert!(y.lo <= -2.0 && y.lo > -2.0001 && y.hi >= 4.0 && y.hi < 4.0001);
+    let y = Interval::new(1.0, 2.0) / Interval::new(4.0, 8.0);
+    assert!(y.contains(0.125) && y.contains(0.5));
+    assert_eq!(-x, Interval::new(-2.0, 1.0));
+    assert_eq!(x.midpoint(), 0.5);
+    assert_eq!(
+        x.intersect(Interval::new(1.5, 3.0)),
+        Some(Interval::new(1.5, 2.0))
+    );
+    assert_eq!(x.intersect(Interval::new(3.0, 4.0)), None);
+}
+
+#[test]
+#[should_panic(expected = "lo must not be greater than hi.")]
+fn test_interval_new_reversed() {
+    Interval::new(1.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "divisor must not contain zero.")]
+fn test_interval_div_by_zero() {
+    let _ = Interval::point(1.0) / Interval::new(-1.0, 1.0);
+}
+
+#[test]
+fn test_poly_eval_interval_methods() {
+    // x^2 - x on [0, 1] has the range [-0.25, 0]
+    let c = [0.0, -1.0, 1.0];
+    let x = Interval::new(0.0, 1.0);
+    let close = |r: Interval, lo: f64, hi: f64| {
+        assert!(r.lo <= lo && r.hi >= hi, "{:?}", r);
+        assert!(r.lo > lo - 1e-12 && r.hi < hi + 1e-12, "{:?}", r);
+    };
+    close(
+        poly_eval_interval(&c, x, None, EnclosureMethod::Horner),
+        -1.0,
+        0.0,
+    );
+    close(
+        poly_eval_interval(&c, x, None, EnclosureMethod::MeanValue),
+        -0.75,
+        0.25,
+    );
+    close(
+        poly_eval_interval(&c, x, None, EnclosureMethod::Bernstein),
+        -0.5,
+        0.0,
+    );
+}
+
+#[test]
+fn test_poly_eval_interval_mean_value_narrow() {
+    // the centered form converges quadratically and beats the naive form on narrow intervals
+    let c = [1.0, -3.0, 0.5, 2.0, -1.0];
+    let x = Interval::new(0.49, 0.51);
+    let horner = poly_eval_interval(&c, x, None, EnclosureMethod::Horner);
+    let mean_value = poly_eval_interval(&c, x, None, EnclosureMethod::MeanValue);
+    assert!(mean_value.width() < horner.width());
+    assert_encloses(&c, x, None);
+}
+
+#[test]
+fn test_poly_eval_interval_encloses() {
+    let c = vec![1.5, -2.0, 0.25, 3.0, -1.0, 0.5];
+    let b = vec![0.3, -0.7, 1.1];
+    for x in [
+        Interval::new(-1.0, 1.0),
+        Interval::new(0.2, 0.3),
+        Interval::new(-3.0, -2.5),
+        Interval::point(0.7),
+    ] {
+        assert_encloses(&c, x, None);
+        assert_encloses(&c, x, Some(&b));
+    }
+}
+
+#[test]
+fn test_poly_eval_interval_rounding() {
+    // (x-1)^5 near 1 suffers cancellation, the enclosure must still contain the exact value
+    let c = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+    let x = 1.0 + 1.0 / 1024.0;
+    for method in METHODS {
+        let range = poly_eval_interval(&c, Interval::point(x), None, method);
+        assert!(range.contains(2.0_f64.powi(-50)), "{:?}", range);
+    }
+
+    // 0.1 is not exact, still the range of 0.1 x over [0, 3] contains 0.1 * 3
+    let range = poly_eval_interval(
+        &[0.0, 0.1],
+        Interval::new(0.0, 3.0),
+        None,
+        EnclosureMethod::Bernstein,
+    );
+    assert!(range.contains(0.1 * 3.0) && range.lo <= 0.0);
+}
+
+#[test]
+fn test_poly_eval_interval_constant() {
+    for method in METHODS {
+        let range = poly_eval_interval(&[2.5], Interval::new(-1.0, 1.0), None, method);
+        assert!(range.contains(2.5) && range.width() <= 1e-14);
+        let range = poly_eval_interval(&[], Interval::new(-1.0, 1.0), None, method);
+        assert_eq!(range, Interval::point(0.0));
+    }
+}
+
+#[test]
+fn test_pascal_triangle_encloses_binomials() {
+    let pascal = pascal_triangle(64);
+    let mut exact: Vec<u128> = vec![1];
+    for (n, row) in pascal.iter().enumerate() {
+        if n > 0 {
+            let mut next = vec![1; n + 1];
+            for j in 1..n {
+                next[j] = exact[j - 1] + exact[j];
+            }
+            exact = next;
+        }
+        for (binomial, e) in row.iter().zip(&exact) {
+            // the endpoints are integers, so the casts are exact
+            assert!(
+                binomial.lo as u128 <= *e && *e <= binomial.hi as u128,
+                "C({}, _) = {} not in {:?}",
+                n,
+                e,
+                binomial
+            );
+        }
+        // C(56, 28) < 2^53 < C(57, 28)
+        let points = row.iter().all(|b| b.lo == b.hi);
+        assert_eq!(points, n <= 56, "n: {}", n);
+    }
+}
+
+#[test]
+fn test_poly_eval_interval_high_degree() {
+    // past degree 56 the binomials are no longer exact
+    for n in [56, 57, 64] {
+        let c: Vec<f64> = (0..=n).map(|k| 1.0 / (k as f64 + 1.0) - 0.3).collect();
+        assert_encloses(&c, Interval::new(0.1, 0.9), None);
+        assert_encloses(&c, Interval::new(-0.5, 0.25), None);
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::evaluator::PolynomialEvaluator;
+use crate::newton_form::newton_to_monomial;
+use crate::poly_eval::poly_eval_into;
+
+/**
+多项式
+
+以单项式（幂基）系数存放的实系数多项式`c0 + c1x + c2x^2 + ...`，`c[0]`为常数项，最高次的系数不为0
+（零多项式没有系数）。支持加、减、乘、与标量的运算和复合，求值使用[`poly_eval`](crate::poly_eval)的SIMD算法。
+
+# 示例
+
+```
+use poly_eval::Polynomial;
+let p = Polynomial::new(&[1.0, 2.0]); // 1 + 2x
+let q = Polynomial::new(&[-1.0, 0.0, 1.0]); // x^2 - 1
+let r = &p * &q + 1.0;
+assert_eq!(r.coefficients(), &[0.0, -2.0, 1.0, 2.0]);
+assert_eq!(r.to_string(), "2x^3 + x^2 - 2x");
+assert_eq!(r.eval(&[1.0, 2.0]), vec![1.0, 16.0]);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polynomial {
+    // ascending coefficients without trailing zeros
+    c: Vec<f64>,
+}
+
+impl Polynomial {
+    /**
+    由单项式系数创建多项式
+
+    # 参数
+
+    - `c`: 多项式的系数，按照从低到高的顺序排列，`c[0]`为常数项，最高次的0会被去掉。
+    */
+    pub fn new(c: &[f64]) -> Self {
+        Self::from_vec(c.to_vec())
+    }
+
+    /**
+    由牛顿形式的系数创建多项式
+
+    把`c0 + (x-b0)(c1 + (x-b1)(c2 + ...))`展开为单项式系数，参见[`newton_to_monomial`](crate::newton_to_monomial)。
+
+    # 参数
+
+    - `c`: 牛顿形式的系数，`c[0]`为常数项。
+    - `b`: 基点（插值点），规则与[`poly_eval`](crate::poly_eval)相同，基点个数多于系数个数减1则会`panic`。
+    */
+    pub fn from_newton(c: &[f64], b: &[f64]) -> Self {
+        Self::from_vec(newton_to_monomial(c, b))
+    }
+
+    /// 零多项式。
+    pub fn zero() -> Self {
+        Polynomial { c: vec![] }
+    }
+
+    /// 常数多项式`a`。
+    pub fn constant(a: f64) -> Self {
+        Self::from_vec(vec![a])
+    }
+
+    /// 一次多项式`x`。
+    pub fn x() -> Self {
+        Polynomial { c: vec![0.0, 1.0] }
+    }
+
+    pub(crate) fn from_vec(mut c: Vec<f64>) -> Self {
+        while c.last() == Some(&0.0) {
+            c.pop();
+        }
+        Polynomial { c }
+    }
+
+    /// 返回单项式系数，`c[0]`为常数项，零多项式返回空的切片。
+    pub fn coefficients(&self) -> &[f64] {
+        &self.c
+    }
+
+    /// 返回多项式的次数，零多项式和常数多项式的次数为0。
+    pub fn degree(&self) -> usize {
+        self.c.len().saturating_sub(1)
+    }
+
+    /// 判断是否为零多项式。
+    pub fn is_zero(&self) -> bool {
+        self.c.is_empty()
+    }
+
+    /**
+    计算多项式的值
+
+    # 参数
+
+    - `x`: 自变量的值。
+
+    # 返回值
+
+    计算得到的多项式值，零多项式的值全部为0。
+    */
+    pub fn eval(&self, x: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; x.len()];
+        poly_eval_into(&self.c, x, None, &mut result);
+        result
+    }
+
+    /// 计算多项式在`x`处的值。
+    pub fn eval_at(&self, x: f64) -> f64 {
+        let mut result = [0.0];
+        poly_eval_into(&self.c, &[x], None, &mut result);
+        result[0]
+    }
+
+    /// 创建对该多项式反复求值的[`PolynomialEvaluator`]。
+    pub fn evaluator(&self) -> PolynomialEvaluator {
+        PolynomialEvaluator::new(&self.c, None)
+    }
+
+    /**
+    多项式复合
+
+    # 参数
+
+    - `other`: 内层多项式`q`。
+
+    # 返回值
+
+    复合多项式`p(q(x))`。
+    */
+    pub fn compose(&self, other: &Polynomial) -> Polynomial {
+        // Horner's method with polynomial arithmetic
+        self.c
+            .iter()
+            .rev()
+            .fold(Polynomial::zero(), |p, c_j| &p * other + *c_j)
+    }
+}
+
+impl From<Vec<f64>> for Polynomial {
+    fn from(c: Vec<f64>) -> Self {
+        Self::from_vec(c)
+    }
+}
+
+impl fmt::Display for Polynomial {
+    /// Prints in mathematical notation from the highest degree, like `2x^3 - x + 0.5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.c.is_empty() {
+            return write!(f, "0");
+        }
+        let precision = f.precision();
+        let format = |a: f64| match precision {
+            Some(precision) => format!("{:.*}", precision, a),
+            None => format!("{}", a),
+        };
+        let mut first = true;
+        for (k, a) in self.c.iter().enumerate().rev() {
+            if *a == 0.0 {
+                continue;
+            }
+            let sign = if a.is_sign_negative() { "-" } else { "+" };
+            if first {
+                if sign == "-" {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {} ", sign)?;
+            }
+            first = false;
+            let magnitude = a.abs();
+            if k == 0 || magnitude != 1.0 {
+                write!(f, "{}", format(magnitude))?;
+            }
+            match k {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", k)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        let (long, short) = if self.c.len() >= rhs.c.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut c = long.c.clone();
+        c.iter_mut().zip(&short.c).for_each(|(a, b)| *a += b);
+        Polynomial::from_vec(c)
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+    fn sub(self, rhs: &Polynomial) -> Polynomial {
+        self + &-rhs
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        if self.c.is_empty() || rhs.c.is_empty() {
+            return Polynomial::zero();
+        }
+        let mut c = vec![0.0; self.c.len() + rhs.c.len() - 1];
+        for (i, a) in self.c.iter().enumerate() {
+            for (j, b) in rhs.c.iter().enumerate() {
+                c[i + j] += a * b;
+            }
+        }
+        Polynomial::from_vec(c)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+    fn neg(self) -> Polynomial {
+        Polynomial {
+            c: self.c.iter().map(|a| -a).collect(),
+        }
+    }
+}
+
+impl Add<f64> for &Polynomial {
+    type Output = Polynomial;
+    fn add(self, rhs: f64) -> Polynomial {
+        self + &Polynomial::constant(rhs)
+    }
+}
+
+impl Sub<f64> for &Polynomial {
+    type Output = Polynomial;
+    fn sub(self, rhs: f64) -> Polynomial {
+        self + &Polynomial::constant(-rhs)
+    }
+}
+
+impl Mul<f64> for &Polynomial {
+    type Output = Polynomial;
+    fn mul(self, rhs: f64) -> Polynomial {
+        Polynomial::from_vec(self.c.iter().map(|a| a * rhs).collect())
+    }
+}
+
+impl Div<f64> for &Polynomial {
+    type Output = Polynomial;
+    fn div(self, rhs: f64) -> Polynomial {
+        Polynomial::from_vec(self.c.iter().map(|a| a / rhs).collect())
+    }
+}
+
+/// Forwards the operators on owned polynomials to the ones on references.
+macro_rules! forward_owned_ops {
+    ($($trait:ident, $method:ident, $rhs:ty;)*) => {
+        $(
+            impl $trait<$rhs> for Polynomial {
+                type Output = Polynomial;
+                fn $method(self, rhs: $rhs) -> Polynomial {
+                    (&self).$method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned_ops! {
+    Add, add, &Polynomial;
+    Sub, sub, &Polynomial;
+    Mul, mul, &Polynomial;
+    Add, add, f64;
+    Sub, sub, f64;
+    Mul, mul, f64;
+    Div, div, f64;
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+    fn add(self, rhs: Polynomial) -> Polynomial {
+        &self + &rhs
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Polynomial;
+    fn sub(self, rhs: Polynomial) -> Polynomial {
+        &self - &rhs
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+    fn mul(self, rhs: Polynomial) -> Polynomial {
+        &self * &rhs
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
@@ -0,0 +1,192 @@
+use std::ops::{Div, Rem};
+
+use crate::polynomial::Polynomial;
+
+impl Polynomial {
+    /**
+    多项式带余除法
+
+    # 参数
+
+    - `divisor`: 除式，不能为零多项式，否则会`panic`。
+
+    # 返回值
+
+    `(商, 余式)`，满足`self = 商 * divisor + 余式`，余式的次数小于除式的次数。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    // x^3 - 1 = (x - 1)(x^2 + x + 1)
+    let p = Polynomial::new(&[-1.0, 0.0, 0.0, 1.0]);
+    let (q, r) = p.div_rem(&Polynomial::new(&[-1.0, 1.0]));
+    assert_eq!(q.coefficients(), &[1.0, 1.0, 1.0]);
+    assert!(r.is_zero());
+    ```
+    */
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        if divisor.is_zero() {
+            panic!("divisor must not be the zero polynomial.");
+        }
+        let d = divisor.coefficients();
+        let n = d.len() - 1;
+        if self.coefficients().len() <= n {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        let mut r = self.coefficients().to_vec();
+        let mut q = vec![0.0; r.len() - n];
+        for k in (0..q.len()).rev() {
+            // eliminate the highest remaining term with q_k x^k * divisor
+            q[k] = r[k + n] / d[n];
+            for j in 0..n {
+                r[k + j] -= q[k] * d[j];
+            }
+            r[k + n] = 0.0;
+        }
+        r.truncate(n);
+        (Polynomial::from_vec(q), Polynomial::from_vec(r))
+    }
+
+    /**
+    综合除法，除以`(x - r)`
+
+    # 参数
+
+    - `r`: 一次因式`x - r`的根。
+
+    # 返回值
+
+    `(商, 余数)`，余数等于`p(r)`。若`r`是根，则商为降阶（deflation）后的多项式。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    let p = Polynomial::new(&[-6.0, 11.0, -6.0, 1.0]); // (x-1)(x-2)(x-3)
+    let (q, remainder) = p.synthetic_division(1.0);
+    assert_eq!(q.coefficients(), &[6.0, -5.0, 1.0]);
+    assert_eq!(remainder, 0.0);
+    ```
+    */
+    pub fn synthetic_division(&self, r: f64) -> (Polynomial, f64) {
+        let c = self.coefficients();
+        if c.is_empty() {
+            return (Polynomial::zero(), 0.0);
+        }
+        let mut q = vec![0.0; c.len() - 1];
+        let mut s = c[c.len() - 1];
+        for k in (0..q.len()).rev() {
+            q[k] = s;
+            s = s * r + c[k];
+        }
+        (Polynomial::from_vec(q), s)
+    }
+
+    /**
+    多项式的最大公因式（带容差）
+
+    使用欧几里得算法，每一步把余式缩放到最大系数的绝对值为1，并把绝对值不超过`tolerance`的系数视为0，
+    因此系数带有舍入误差的多项式也能找到公因式。
+
+    # 参数
+
+    - `other`: 另一个多项式。
+    - `tolerance`: 相对容差，例如`1e-10`。
+
+    # 返回值
+
+    首一（最高次系数为1）的最大公因式，两者都是零多项式时返回零多项式，没有公因式时返回常数1。
+
+    # 示例
+
+    ```
+    use poly_eval::Polynomial;
+    let p = Polynomial::new(&[2.0, -3.0, 1.0]); // (x-1)(x-2)
+    let q = Polynomial::new(&[3.0, -4.0, 1.0]); // (x-1)(x-3)
+    let g = p.gcd(&q, 1e-10); // x - 1
+    assert!((g.coefficients()[0] + 1.0).abs() < 1e-12 && g.coefficients()[1] == 1.0);
+    ```
+    */
+    pub fn gcd(&self, other: &Polynomial, tolerance: f64) -> Polynomial {
+        let mut a = self.normalized();
+        let mut b = other.normalized();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            // a and b are scaled to 1, so the remainder is compared with the tolerance directly
+            let r = Polynomial::from_vec(
+                r.coefficients()
+                    .iter()
+                    .map(|c| if c.abs() <= tolerance { 0.0 } else { *c })
+                    .collect(),
+            );
+            a = b;
+            b = r.normalized();
+        }
+        a.monic()
+    }
+
+    /// 返回首一多项式（除以最高次系数），零多项式返回零多项式。
+    pub fn monic(&self) -> Polynomial {
+        match self.coefficients().last() {
+            Some(lead) => self / *lead,
+            None => Polynomial::zero(),
+        }
+    }
+
+    /// Scales the coefficients so that the largest absolute value is 1.
+    fn normalized(&self) -> Polynomial {
+        let scale = self
+            .coefficients()
+            .iter()
+            .fold(0.0f64, |m, c| m.max(c.abs()));
+        if scale == 0.0 {
+            Polynomial::zero()
+        } else {
+            self / scale
+        }
+    }
+}
+
+impl Div for &Polynomial {
+    type Output = Polynomial;
+    fn div(self, rhs: &Polynomial) -> Polynomial {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &Polynomial {
+    type Output = Polynomial;
+    fn rem(self, rhs: &Polynomial) -> Polynomial {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Div<&Polynomial> for Polynomial {
+    type Output = Polynomial;
+    fn div(self, rhs: &Polynomial) -> Polynomial {
+        &self / rhs
+    }
+}
+
+impl Rem<&Polynomial> for Polynomial {
+    type Output = Polynomial;
+    fn rem(self, rhs: &Polynomial) -> Polynomial {
+        &self % rhs
+    }
+}
+
+impl Div for Polynomial {
+    type Output = Polynomial;
+    fn div(self, rhs: Polynomial) -> Polynomial {
+        &self / &rhs
+    }
+}
+
+impl Rem for Polynomial {
+    type Output = Polynomial;
+    fn rem(self, rhs: Polynomial) -> Polynomial {
+        &self % &rhs
+    }
+}
@@ -0,0 +1,111 @@
+use crate::polynomial::Polynomial;
+
+fn from_roots(roots: &[f64]) -> Polynomial {
+    roots.iter().fold(Polynomial::constant(1.0), |p, r| {
+        &p * &Polynomial::new(&[-r, 1.0])
+    })
+}
+
+fn assert_close(p: &Polynomial, expected: &[f64], tolerance: f64) {
+    assert_eq!(p.coefficients().len(), expected.len(), "{}", p);
+    for (a, b) in p.coefficients().iter().zip(expected) {
+        assert!((a - b).abs() <= tolerance, "{} {:?}", p, expected);
+    }
+}
+
+#[test]
+fn test_div_rem() {
+    // 2x^4 + 3x^3 - x + 5 = (x^2 + 1)(2x^2 + 3x - 2) + (-4x + 7)
+    let p = Polynomial::new(&[5.0, -1.0, 0.0, 3.0, 2.0]);
+    let d = Polynomial::new(&[1.0, 0.0, 1.0]);
+    let (q, r) = p.div_rem(&d);
+    assert_eq!(q.coefficients(), &[-2.0, 3.0, 2.0]);
+    assert_eq!(r.coefficients(), &[7.0, -4.0]);
+    assert_eq!(&(&q * &d) + &r, p);
+    assert_eq!(&p / &d, q);
+    assert_eq!(&p % &d, r);
+    assert_eq!(p.clone() / &d, q);
+    assert_eq!(p.clone() % &d, r);
+    assert_eq!(p.clone() / d.clone(), q);
+    assert_eq!(p % d, r);
+}
+
+#[test]
+fn test_div_rem_low_degree() {
+    let p = Polynomial::new(&[1.0, 2.0]);
+    let (q, r) = p.div_rem(&Polynomial::new(&[0.0, 0.0, 1.0]));
+    assert!(q.is_zero());
+    assert_eq!(r, p);
+    let (q, r) = p.div_rem(&Polynomial::constant(2.0));
+    assert_eq!(q.coefficients(), &[0.5, 1.0]);
+    assert!(r.is_zero());
+    let (q, r) = Polynomial::zero().div_rem(&p);
+    assert!(q.is_zero() && r.is_zero());
+}
+
+#[test]
+#[should_panic]
+fn test_div_rem_by_zero() {
+    Polynomial::new(&[1.0]).div_rem(&Polynomial::zero());
+}
+
+#[test]
+fn test_synthetic_division() {
+    let p = from_roots(&[1.0, 2.0, 3.0, 4.0]);
+    let (q, remainder) = p.synthetic_division(4.0);
+    assert_eq!(q, from_roots(&[1.0, 2.0, 3.0]));
+    assert_eq!(remainder, 0.0);
+
+    // the remainder is p(r)
+    let p = Polynomial::new(&[5.0, -1.0, 0.0, 3.0, 2.0]);
+    let (q, remainder) = p.synthetic_division(1.5);
+    assert_eq!(remainder, p.eval_at(1.5));
+    assert_eq!(q, p.div_rem(&Polynomial::new(&[-1.5, 1.0])).0);
+    assert_eq!(
+        Polynomial::zero().synthetic_division(1.0),
+        (Polynomial::zero(), 0.0)
+    );
+}
+
+#[test]
+fn test_gcd_exact() {
+    let p = from_roots(&[1.0, 2.0, -3.0]);
+    let q = from_roots(&[2.0, -3.0, 5.0, 7.0]);
+    assert_close(
+        &p.gcd(&q, 1e-10),
+        from_roots(&[2.0, -3.0]).coefficients(),
+        1e-12,
+    );
+    assert_close(
+        &q.gcd(&p, 1e-10),
+        from_roots(&[2.0, -3.0]).coefficients(),
+        1e-12,
+    );
+}
+
+#[test]
+fn test_gcd_perturbed() {
+    // the common root 0.5 is perturbed by rounding, plain Euclid would find no common factor
+    let p = from_roots(&[0.1, 0.5, 0.7]);
+    let q = &from_roots(&[0.5 + 1e-13, 0.9]) * 3.0;
+    let g = p.gcd(&q, 1e-9);
+    assert_close(&g, &[-0.5, 1.0], 1e-9);
+    assert_eq!(p.gcd(&q, 0.0).degree(), 0);
+}
+
+#[test]
+fn test_gcd_special() {
+    let p = Polynomial::new(&[2.0, 4.0]);
+    assert_eq!(
+        p.gcd(&Polynomial::zero(), 1e-10).coefficients(),
+        &[0.5, 1.0]
+    );
+    assert_eq!(
+        Polynomial::zero().gcd(&p, 1e-10).coefficients(),
+        &[0.5, 1.0]
+    );
+    assert!(Polynomial::zero().gcd(&Polynomial::zero(), 1e-10).is_zero());
+    let coprime = from_roots(&[1.0]).gcd(&from_roots(&[2.0]), 1e-10);
+    assert_eq!(coprime, Polynomial::constant(1.0));
+    assert_eq!(p.monic().coefficients(), &[0.5, 1.0]);
+}
@@ -0,0 +1,99 @@
+use crate::poly_eval::poly_eval;
+use crate::polynomial::Polynomial;
+
+#[test]
+fn test_polynomial_new_trims_zeros() {
+    let p = Polynomial::new(&[1.0, 2.0, 0.0, 0.0]);
+    assert_eq!(p.coefficients(), &[1.0, 2.0]);
+    assert_eq!(p.degree(), 1);
+    assert!(Polynomial::new(&[0.0, 0.0]).is_zero());
+    assert_eq!(Polynomial::new(&[0.0]), Polynomial::zero());
+    assert_eq!(Polynomial::zero().degree(), 0);
+    assert_eq!(Polynomial::from(vec![3.0, 0.0]), Polynomial::constant(3.0));
+}
+
+#[test]
+fn test_polynomial_from_newton() {
+    let c = [1.0, 2.0, 3.0, 4.0];
+    let b = [3.0, 4.0, 5.0];
+    let p = Polynomial::from_newton(&c, &b);
+    // 1 + 2(x-3) + 3(x-3)(x-4) + 4(x-3)(x-4)(x-5)
+    assert_eq!(p.coefficients(), &[-209.0, 169.0, -45.0, 4.0]);
+    let x: Vec<f64> = (0..12).map(|i| i as f64).collect();
+    assert_eq!(p.eval(&x), poly_eval(&c, &x, Some(&b.to_vec())));
+    // missing base points are 0
+    assert_eq!(
+        Polynomial::from_newton(&[1.0, 2.0, 3.0], &[]),
+        Polynomial::new(&[1.0, 2.0, 3.0])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_polynomial_from_newton_too_many_base() {
+    Polynomial::from_newton(&[1.0, 2.0], &[1.0, 2.0]);
+}
+
+#[test]
+fn test_polynomial_eval() {
+    let p = Polynomial::new(&[1.0, 2.0, 3.0]);
+    let x: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+    assert_eq!(p.eval(&x), poly_eval(&[1.0, 2.0, 3.0], &x, None));
+    assert_eq!(p.eval_at(2.0), 17.0);
+    assert_eq!(p.evaluator().eval(&x), p.eval(&x));
+    assert_eq!(Polynomial::zero().eval(&[1.0, 2.0]), vec![0.0, 0.0]);
+    assert_eq!(Polynomial::zero().eval_at(1.0), 0.0);
+}
+
+#[test]
+fn test_polynomial_arithmetic() {
+    let p = Polynomial::new(&[1.0, 2.0]);
+    let q = Polynomial::new(&[-1.0, 0.0, 1.0]);
+    assert_eq!((&p + &q).coefficients(), &[0.0, 2.0, 1.0]);
+    assert_eq!((&p - &q).coefficients(), &[2.0, 2.0, -1.0]);
+    assert_eq!((&q - &q), Polynomial::zero());
+    assert_eq!((&p * &q).coefficients(), &[-1.0, -2.0, 1.0, 2.0]);
+    assert_eq!(&p * &Polynomial::zero(), Polynomial::zero());
+    assert_eq!((-&p).coefficients(), &[-1.0, -2.0]);
+    assert_eq!((&p + 1.0).coefficients(), &[2.0, 2.0]);
+    assert_eq!((&p - 1.0).coefficients(), &[0.0, 2.0]);
+    assert_eq!((&p * 3.0).coefficients(), &[3.0, 6.0]);
+    assert_eq!((&p / 2.0).coefficients(), &[0.5, 1.0]);
+    assert_eq!(&p * 0.0, Polynomial::zero());
+    // owned operands
+    assert_eq!(p.clone() + q.clone(), &p + &q);
+    assert_eq!(p.clone() - &q, &p - &q);
+    assert_eq!(p.clone() * q.clone(), &p * &q);
+    assert_eq!(-p.clone() * 2.0, Polynomial::new(&[-2.0, -4.0]));
+}
+
+#[test]
+fn test_polynomial_compose() {
+    let p = Polynomial::new(&[1.0, 0.0, 1.0]); // 1 + x^2
+    let q = Polynomial::new(&[-1.0, 2.0]); // 2x - 1
+    let r = p.compose(&q);
+    assert_eq!(r.coefficients(), &[2.0, -4.0, 4.0]);
+    assert_eq!(q.compose(&p).coefficients(), &[1.0, 0.0, 2.0]);
+    assert_eq!(p.compose(&Polynomial::x()), p);
+    assert_eq!(
+        p.compose(&Polynomial::constant(2.0)),
+        Polynomial::constant(5.0)
+    );
+    assert_eq!(Polynomial::zero().compose(&p), Polynomial::zero());
+}
+
+#[test]
+fn test_polynomial_display() {
+    assert_eq!(
+        Polynomial::new(&[1.0, -2.0, 3.0]).to_string(),
+        "3x^2 - 2x + 1"
+    );
+    assert_eq!(Polynomial::new(&[0.5, 0.0, -1.0]).to_string(), "-x^2 + 0.5");
+    assert_eq!(Polynomial::x().to_string(), "x");
+    assert_eq!(Polynomial::constant(-1.0).to_string(), "-1");
+    assert_eq!(Polynomial::zero().to_string(), "0");
+    assert_eq!(
+        format!("{:.2}", Polynomial::new(&[1.0 / 3.0, 1.0, 2.5])),
+        "2.50x^2 + x + 0.33"
+    );
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+use crate::backend::Backend;
+use crate::polynomial::Polynomial;
+
+/**
+有理函数
+
+形如`p(x) / q(x)`的有理函数，`p`和`q`为以单项式系数存放的多项式。求值时在同一次遍历中用SIMD的Horner算法
+同时计算分子和分母，再相除，构造时选定计算后端（默认为[`Backend::selected`]）。对于光滑函数，有理逼近（例如[`RationalFunction::pade`]得到的帕德逼近）
+通常比同样多系数的多项式精确得多。
+
+# 示例
+
+```
+use poly_eval::{Polynomial, RationalFunction};
+// (1 + x) / (1 - x)
+let r = RationalFunction::new(Polynomial::new(&[1.0, 1.0]), Polynomial::new(&[1.0, -1.0]));
+assert_eq!(r.eval(&[0.0, 0.5, 3.0]), vec![1.0, 3.0, -2.0]);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction {
+    numerator: Polynomial,
+    denominator: Polynomial,
+    // reversed coefficients padded to the same length, the highest degree first
+    p: Vec<f64>,
+    q: Vec<f64>,
+    backend: Backend,
+}
+
+impl RationalFunction {
+    /**
+    由分子和分母创建有理函数
+
+    # 参数
+
+    - `numerator`: 分子多项式`p`。
+    - `denominator`: 分母多项式`q`，不能为零多项式，否则会`panic`。
+    */
+    pub fn new(numerator: Polynomial, denominator: Polynomial) -> Self {
+        Self::create(numerator, denominator, Backend::selected())
+    }
+
+    /**
+    使用指定的计算后端创建有理函数
+
+    与[`new`](Self::new)相同，但强制使用`backend`，参见[`Backend`]。
+
+    # 参数
+
+    - `numerator`: 分子多项式`p`。
+    - `denominator`: 分母多项式`q`，不能为零多项式，否则会`panic`。
+    - `backend`: 强制使用的计算后端。
+
+    # 返回值
+
+    当前CPU不支持`backend`时返回`None`。
+    */
+    pub fn with_backend(
+        numerator: Polynomial,
+        denominator: Polynomial,
+        backend: Backend,
+    ) -> Option<Self> {
+        if !backend.is_supported() {
+            return None;
+        }
+        Some(Self::create(numerator, denominator, backend))
+    }
+
+    fn create(numerator: Polynomial, denominator: Polynomial, backend: Backend) -> Self {
+        assert!(
+            !denominator.is_zero(),
+            "denominator must not be the zero polynomial."
+        );
+        let n = numerator
+            .coefficients()
+            .len()
+            .max(denominator.coefficients().len());
+        let reversed = |c: &[f64]| {
+            let mut c = c.to_vec();
+            c.resize(n, 0.0);
+            c.reverse();
+            c
+        };
+        let p = reversed(numerator.coefficients());
+        let q = reversed(denominator.coefficients());
+        RationalFunction {
+            numerator,
+            denominator,
+            p,
+            q,
+            backend,
+        }
+    }
+
+    /**
+    由泰勒系数构造帕德逼近
+
+    求分子为`m`次、分母为`n`次（常数项为1）的有理函数，使其泰勒展开的前`m+n+1`项与给定的系数相同。
+
+    # 参数
+
+    - `taylor`: 泰勒系数，`taylor[k]`为`x^k`的系数，个数必须不少于`m+n+1`，否则会`panic`。
+    - `m`: 分子的次数。
+    - `n`: 分母的次数。
+
+    # 返回值
+
+    帕德逼近，如果分母系数满足的线性方程组奇异（该阶的帕德逼近不存在或不唯一）则返回`None`。
+
+    # 示例
+
+    ```
+    use poly_eval::RationalFunction;
+    // exp(x) ≈ (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+    let taylor = [1.0, 1.0, 1.0 / 2.0, 1.0 / 6.0, 1.0 / 24.0];
+    let r = RationalFunction::pade(&taylor, 2, 2).unwrap();
+    assert!((r.eval_at(1.0) - 1.0_f64.exp()).abs() < 4e-3);
+    ```
+    */
+    pub fn pade(taylor: &[f64], m: usize, n: usize) -> Option<Self> {
+        assert!(
+            taylor.len() > m + n,
+            "taylor coefficients' numbers must be at least m+n+1."
+        );
+        let t = |i: usize, j: usize| if i < j { 0.0 } else { taylor[i - j] };
+
+        // sum_{j=1}^{n} q_j t_{m+k-j} = -t_{m+k}, k = 1..n
+        let mut a: Vec<Vec<f64>> = (1..=n)
+            .map(|k| {
+                let mut row: Vec<f64> = (1..=n).map(|j| t(m + k, j)).collect();
+                row.push(-taylor[m + k]);
+                row
+            })
+            .collect();
+        let mut q = vec![1.0];
+        q.extend(solve(&mut a)?);
+
+        // p_i = sum_{j=0}^{min(i, n)} q_j t_{i-j}
+        let p: Vec<f64> = (0..=m)
+            .map(|i| (0..=i.min(n)).map(|j| q[j] * taylor[i - j]).sum())
+            .collect();
+        Some(Self::new(Polynomial::from(p), Polynomial::from(q)))
+    }
+
+    /// 返回分子多项式。
+    pub fn numerator(&self) -> &Polynomial {
+        &self.numerator
+    }
+
+    /// 返回分母多项式。
+    pub fn denominator(&self) -> &Polynomial {
+        &self.denominator
+    }
+
+    /// 返回有理函数使用的计算后端。
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /**
+    计算有理函数的值
+
+    # 参数
+
+    - `x`: 自变量的值。
+
+    # 返回值
+
+    计算得到的函数值，分母为0处按照IEEE 754的规则得到无穷大或NaN。
+    */
+    pub fn eval(&self, x: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; x.len()];
+        self.eval_into(x, &mut result);
+        result
+    }
+
+    /**
+    计算有理函数的值（写入调用者提供的缓冲区）
+
+    如果`x`的个数不少于8个，则对8的整数倍部分使用[`backend`](Self::backend)的SIMD内核
+    （`x86_64`的AVX512F、AVX2或SSE2，`aarch64`的NEON），剩余部分使用普通的算法。
+
+    # 参数
+
+    - `x`: 自变量的值。
+    - `result`: 存放结果的缓冲区，长度必须与`x`相同，否则会`panic`。
+    */
+    pub fn eval_into(&self, x: &[f64], result: &mut [f64]) {
+        assert_eq!(
+            x.len(),
+            result.len(),
+            "result must have the same length as x."
+        );
+        let aligned = if x.len() >= 8 && self.backend != Backend::Scalar {
+            // use simd only when x length >= 8, and no simd for the last few elements
+            x.len() - x.len() % 8
+        } else {
+            0
+        };
+        let pq = (&self.p[..], &self.q[..]);
+        rational_eval_simd(&x[..aligned], pq, self.backend, &mut result[..aligned]);
+        rational_eval(&x[aligned..], (&self.p, &self.q), &mut result[aligned..]);
+    }
+
+    /// 计算有理函数在`x`处的值。
+    pub fn eval_at(&self, x: f64) -> f64 {
+        let mut result = [0.0];
+        rational_eval(&[x], (&self.p, &self.q), &mut result);
+        result[0]
+    }
+
+    #[cfg(test)]
+    pub(crate) fn eval_no_simd(&self, x: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; x.len()];
+        rational_eval(x, (&self.p, &self.q), &mut result);
+        result
+    }
+}
+
+impl fmt::Display for RationalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}) / ({})", self.numerator, self.denominator)
+    }
+}
+
+/// Solves the augmented system `a` by Gaussian elimination with partial pivoting, returns `None`
+/// when it is singular.
+fn solve(a: &mut [Vec<f64>]) -> Option<Vec<f64>> {
+    let n = a.len();
+    let scale = a
+        .iter()
+        .flat_map(|row| row[..n].iter())
+        .fold(0.0_f64, |m, v| m.max(v.abs()));
+    for k in 0..n {
+        let pivot = (k..n).max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))?;
+        if a[pivot][k].abs() <= scale * n as f64 * f64::EPSILON {
+            return None;
+        }
+        a.swap(k, pivot);
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let row_k = &upper[k];
+        for row_i in lower {
+            let factor = row_i[k] / row_k[k];
+            for (a_ij, a_kj) in row_i[k..].iter_mut().zip(&row_k[k..]) {
+                *a_ij -= factor * a_kj;
+            }
+        }
+    }
+    let mut x = vec![0.0; n];
+    for k in (0..n).rev() {
+        let sum: f64 = (k + 1..n).map(|j| a[k][j] * x[j]).sum();
+        x[k] = (a[k][n] - sum) / a[k][k];
+    }
+    Some(x)
+}
+
+/// Evaluates with the SIMD kernel of a supported `backend`, the length of `x` must be a multiple
+/// of 8.
+fn rational_eval_simd(x: &[f64], pq: (&[f64], &[f64]), backend: Backend, result: &mut [f64]) {
+    if x.is_empty() {
+        return;
+    }
+
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse2 => unsafe { crate::x86_64::sse2_rational_eval(x, pq, result) },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 | Backend::Avx2Fma => unsafe {
+            crate::x86_64::avx2_rational_eval(x, pq, result)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 | Backend::Avx512Fma => unsafe {
+            crate::x86_64::avx512_rational_eval(x, pq, result)
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon | Backend::NeonFma => unsafe {
+            crate::aarch64_neon::neon_rational_eval(x, pq, result)
+        },
+        _ => rational_eval(x, pq, result),
+    }
+}
+
+/// Horner's method for the reversed numerator `p` and denominator `q` of the same length in one
+/// pass, followed by the division, the scalar reference of the `*_rational_eval` kernels.
+pub(crate) fn rational_eval(x: &[f64], (p, q): (&[f64], &[f64]), result: &mut [f64]) {
+    for (x_i, y_i) in x.iter().zip(result.iter_mut()) {
+        let (mut num, mut den) = (p[0], q[0]);
+        for (p_j, q_j) in p[1..].iter().zip(&q[1..]) {
+            num = num * x_i + p_j;
+            den = den * x_i + q_j;
+        }
+        *y_i = num / den;
+    }
+}
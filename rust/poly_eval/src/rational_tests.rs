@@ -0,0 +1,150 @@
+use crate::backend::Backend;
+use crate::polynomial::Polynomial;
+use crate::rational::RationalFunction;
+
+fn exp_taylor(n: usize) -> Vec<f64> {
+    let mut taylor = vec![1.0];
+    for k in 1..n {
+        taylor.push(taylor[k - 1] / k as f64);
+    }
+    taylor
+}
+
+#[test]
+fn test_rational_function_eval() {
+    // (x^2 - 1) / (x + 2)
+    let r = RationalFunction::new(
+        Polynomial::new(&[-1.0, 0.0, 1.0]),
+        Polynomial::new(&[2.0, 1.0]),
+    );
+    let x: Vec<f64> = (0..19).map(|i| i as f64).collect();
+    let expected: Vec<f64> = x.iter().map(|x| (x * x - 1.0) / (x + 2.0)).collect();
+    assert_eq!(r.eval(&x), expected);
+    assert_eq!(r.eval_at(3.0), 1.6);
+    assert_eq!(r.eval(&[]), vec![]);
+
+    // pole of the denominator
+    assert_eq!(r.eval_at(-2.0), f64::INFINITY);
+    assert_eq!(r.to_string(), "(x^2 - 1) / (x + 2)");
+}
+
+#[test]
+#[should_panic(expected = "denominator must not be the zero polynomial.")]
+fn test_rational_function_zero_denominator() {
+    RationalFunction::new(Polynomial::x(), Polynomial::zero());
+}
+
+#[test]
+fn test_rational_function_simd_same_as_scalar() {
+    let r = RationalFunction::new(
+        Polynomial::new(&[0.3, -1.2, 0.5, 2.0, -0.7]),
+        Polynomial::new(&[1.0, 0.25, -0.125]),
+    );
+    for n in [7, 8, 9, 16, 29] {
+        let x: Vec<f64> = (0..n).map(|i| 0.17 * i as f64 - 1.3).collect();
+        assert_eq!(r.eval(&x), r.eval_no_simd(&x), "n: {}", n);
+    }
+}
+
+#[test]
+fn test_rational_function_with_backend() {
+    let (p, q) = (
+        Polynomial::new(&[0.3, -1.2, 0.5, 2.0, -0.7]),
+        Polynomial::new(&[1.0, 0.25, -0.125]),
+    );
+    let x: Vec<f64> = (0..29).map(|i| 0.17 * i as f64 - 1.3).collect();
+    let expected = RationalFunction::new(p.clone(), q.clone()).eval_no_simd(&x);
+    for backend in Backend::ALL {
+        match RationalFunction::with_backend(p.clone(), q.clone(), backend) {
+            Some(r) => {
+                assert_eq!(r.backend(), backend);
+                assert_eq!(r.eval(&x), expected, "backend: {:?}", backend);
+            }
+            None => assert!(!backend.is_supported()),
+        }
+    }
+    assert_eq!(RationalFunction::new(p, q).backend(), Backend::selected());
+}
+
+#[test]
+fn test_pade_exp() {
+    // [2/2] of exp(x) is (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+    let r = RationalFunction::pade(&exp_taylor(5), 2, 2).unwrap();
+    let expected_p = [1.0, 0.5, 1.0 / 12.0];
+    let expected_q = [1.0, -0.5, 1.0 / 12.0];
+    for (a, e) in r.numerator().coefficients().iter().zip(&expected_p) {
+        assert!((a - e).abs() <= 1e-15);
+    }
+    for (a, e) in r.denominator().coefficients().iter().zip(&expected_q) {
+        assert!((a - e).abs() <= 1e-15);
+    }
+
+    // more accurate than the Taylor polynomial of the same number of coefficients
+    let taylor = Polynomial::new(&exp_taylor(5));
+    let pade_error = (r.eval_at(1.0) - 1.0_f64.exp()).abs();
+    let taylor_error = (taylor.eval_at(1.0) - 1.0_f64.exp()).abs();
+    assert!(pade_error < taylor_error / 2.0);
+
+    // [m/0] is the Taylor polynomial
+    let r = RationalFunction::pade(&exp_taylor(4), 3, 0).unwrap();
+    assert_eq!(r.numerator(), &Polynomial::new(&exp_taylor(4)));
+    assert_eq!(r.denominator(), &Polynomial::constant(1.0));
+}
+
+#[test]
+fn test_pade_log() {
+    // log(1 + x) = x - x^2/2 + x^3/3 - ..., [3/3] is accurate far beyond the radius of convergence
+    let taylor: Vec<f64> = (0..7)
+        .map(|k| match k {
+            0 => 0.0,
+            _ if k % 2 == 1 => 1.0 / k as f64,
+            _ => -1.0 / k as f64,
+        })
+        .collect();
+    let r = RationalFunction::pade(&taylor, 3, 3).unwrap();
+    assert!((r.eval_at(2.0) - 3.0_f64.ln()).abs() < 1e-3);
+    assert!((r.eval_at(0.1) - 1.1_f64.ln()).abs() < 1e-10);
+}
+
+#[test]
+fn test_pade_singular() {
+    // t_1 q_1 = -t_2 has no solution for x^2
+    assert_eq!(RationalFunction::pade(&[0.0, 0.0, 1.0], 1, 1), None);
+}
+
+#[test]
+#[should_panic(expected = "taylor coefficients' numbers must be at least m+n+1.")]
+fn test_pade_not_enough_coefficients() {
+    RationalFunction::pade(&[1.0, 1.0], 1, 1);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_rational_eval_kernels_same_as_scalar() {
+    use crate::rational::rational_eval;
+    use crate::test_support::assert_kernels_eq;
+    use crate::x86_64::{avx2_rational_eval, avx512_rational_eval, sse2_rational_eval};
+
+    let p: Vec<f64> = (0..9).map(|i| 1.0 / (i as f64 + 1.0) - 0.4).collect();
+    let q: Vec<f64> = (0..9).map(|i| 0.2 * i as f64 - 0.3).collect();
+    let x: Vec<f64> = (0..16).map(|i| 0.11 * i as f64 - 0.5).collect();
+
+    let mut expected = vec![0.0; x.len()];
+    rational_eval(&x, (&p, &q), &mut expected);
+
+    assert_kernels_eq(
+        &expected,
+        0.0,
+        &[
+            (Backend::Sse2, &|r| unsafe {
+                sse2_rational_eval(&x, (&p, &q), r)
+            }),
+            (Backend::Avx2, &|r| unsafe {
+                avx2_rational_eval(&x, (&p, &q), r)
+            }),
+            (Backend::Avx512, &|r| unsafe {
+                avx512_rational_eval(&x, (&p, &q), r)
+            }),
+        ],
+    );
+}
@@ -0,0 +1,25 @@
+//! Helpers shared by the unit tests.
+
+use std::fmt::Debug;
+
+use crate::backend::Backend;
+
+/// A SIMD kernel writing its results into the buffer, run only when its backend is supported.
+pub(crate) type Kernel<'a, T> = (Backend, &'a dyn Fn(&mut [T]));
+
+/// Asserts that every kernel whose backend is supported by the CPU produces exactly `expected`,
+/// each kernel writes into a buffer of `expected.len()` copies of `zero`.
+pub(crate) fn assert_kernels_eq<T: Clone + PartialEq + Debug>(
+    expected: &[T],
+    zero: T,
+    kernels: &[Kernel<T>],
+) {
+    for (backend, kernel) in kernels {
+        if !backend.is_supported() {
+            continue;
+        }
+        let mut result = vec![zero.clone(); expected.len()];
+        kernel(&mut result);
+        assert_eq!(result, expected, "backend: {:?}", backend);
+    }
+}
@@ -1,7 +1,16 @@
-#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-#[target_feature(enable = "sse3")]
+//! SIMD kernels for `x86_64`.
+//!
+//! Every kernel performs exactly the same floating-point operations, in the same order, as the
+//! scalar reference called by its dispatcher for the tail of `x`, so their results are bitwise
+//! identical. Only the kernels with `fma` in their name fuse a multiply and an add, and their
+//! reference is the matching `mul_add` loop. The dispatchers pick the kernel from a
+//! [`Backend`](crate::Backend), never by detecting CPU features themselves.
+//! `test_support::assert_kernels_eq` checks each kernel against its reference.
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
 #[inline]
-pub(crate) unsafe fn sse2_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result: &mut Vec<f64>) {
+pub(crate) unsafe fn sse2_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
     use std::arch::x86_64::*;
 
     for i in (0..x.len()).step_by(2) {
@@ -11,8 +20,8 @@ pub(crate) unsafe fn sse2_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result
 
         (0..c.len()).for_each(|j| {
             // load next  element of c and b to reg and duplicate it, notice that c and b are reversed
-            let c_jx1 = _mm_loaddup_pd(c.as_ptr().add(j));
-            let base_jx1 = _mm_loaddup_pd(base.as_ptr().add(j));
+            let c_jx1 = _mm_set1_pd(c[j]);
+            let base_jx1 = _mm_set1_pd(base[j]);
             let sub_0 = _mm_sub_pd(x_ix2, base_jx1);
             let add_0 = _mm_add_pd(c_jx1, y_i_0);
             if j + 1 < c.len() {
@@ -27,10 +36,10 @@ pub(crate) unsafe fn sse2_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result
     }
 }
 
-#[cfg(all(target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx2")]
 #[inline]
-pub(crate) unsafe fn avx2_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result: &mut Vec<f64>) {
+pub(crate) unsafe fn avx2_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
     use std::arch::x86_64::*;
 
     for i in (0..x.len()).step_by(4) {
@@ -58,29 +67,918 @@ pub(crate) unsafe fn avx2_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result
     }
 }
 
-#[cfg(all(target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512f")]
 #[inline]
-pub(crate) unsafe fn avx512_eval(x: &Vec<f64>, c: Vec<f64>, base: Vec<f64>, result: &mut Vec<f64>) {
+pub(crate) unsafe fn avx512_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
     use std::arch::x86_64::*;
 
     for i in (0..x.len()).step_by(8) {
-        // load 4 x to reg
+        // load 8 x to reg
         let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
         let mut y_ix8 = _mm512_setzero_pd();
 
         for j in 0..c.len() {
-            // load next 4 elements of c and b to reg and duplicate it, notice that c and b are reversed
+            // load next element of c and b to reg and duplicate it, notice that c and b are reversed
             let c_j_1x = _mm_loaddup_pd(c.as_ptr().add(j));
             let c_j_8x = _mm512_broadcastsd_pd(c_j_1x);
             let base_jx1 = _mm_loaddup_pd(base.as_ptr().add(j));
             let base_jx8 = _mm512_broadcastsd_pd(base_jx1);
-            // calc xi_0 with 4 elements c and b, (xi_0-b3)((xi_0-b2)((xi_0-b1)((xi_0-b0)c0+c1)+c2)+c3)
+            // calc (x-b_j)(c_j+y), the last c is only added
             let sub_0 = _mm512_sub_pd(x_ix8, base_jx8);
             let add_0 = _mm512_add_pd(c_j_8x, y_ix8);
-            y_ix8 = _mm512_mul_pd(sub_0, add_0);
+            if j + 1 < c.len() {
+                y_ix8 = _mm512_mul_pd(sub_0, add_0);
+            } else {
+                y_ix8 = add_0;
+            }
         }
         let ptr = result.as_mut_ptr().add(i);
         _mm512_storeu_pd(ptr, y_ix8)
     }
 }
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,fma")]
+#[inline]
+pub(crate) unsafe fn avx2_fma_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let mut y_ix4 = _mm256_set1_pd(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j with a single rounding, notice that c and b are reversed
+            let c_jx4 = _mm256_set1_pd(c[j]);
+            let base_jx4 = _mm256_set1_pd(base[j - 1]);
+            let sub_0 = _mm256_sub_pd(x_ix4, base_jx4);
+            y_ix4 = _mm256_fmadd_pd(sub_0, y_ix4, c_jx4);
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm256_storeu_pd(ptr, y_ix4);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,fma")]
+#[inline]
+pub(crate) unsafe fn avx2_fma_monomial_eval(x: &[f64], c: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let mut y_ix4 = _mm256_set1_pd(c[0]);
+
+        for c_j in &c[1..] {
+            // calc xy+c_j with a single rounding, notice that c is reversed
+            y_ix4 = _mm256_fmadd_pd(x_ix4, y_ix4, _mm256_set1_pd(*c_j));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm256_storeu_pd(ptr, y_ix4);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_fma_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        // load 8 x to reg
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let mut y_ix8 = _mm512_set1_pd(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j with a single rounding, notice that c and b are reversed
+            let c_jx8 = _mm512_set1_pd(c[j]);
+            let base_jx8 = _mm512_set1_pd(base[j - 1]);
+            let sub_0 = _mm512_sub_pd(x_ix8, base_jx8);
+            y_ix8 = _mm512_fmadd_pd(sub_0, y_ix8, c_jx8);
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm512_storeu_pd(ptr, y_ix8);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_fma_monomial_eval(x: &[f64], c: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        // load 8 x to reg
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let mut y_ix8 = _mm512_set1_pd(c[0]);
+
+        for c_j in &c[1..] {
+            // calc xy+c_j with a single rounding, notice that c is reversed
+            y_ix8 = _mm512_fmadd_pd(x_ix8, y_ix8, _mm512_set1_pd(*c_j));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm512_storeu_pd(ptr, y_ix8);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_comp_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    // error-free transformation a + b = s + e
+    #[inline(always)]
+    unsafe fn two_sum(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+        let s = _mm_add_pd(a, b);
+        let bb = _mm_sub_pd(s, a);
+        let e = _mm_add_pd(_mm_sub_pd(a, _mm_sub_pd(s, bb)), _mm_sub_pd(b, bb));
+        (s, e)
+    }
+
+    // split a into two 26-bit halves (Veltkamp)
+    #[inline(always)]
+    unsafe fn split(a: __m128d) -> (__m128d, __m128d) {
+        let c = _mm_mul_pd(_mm_set1_pd(134217729.0), a);
+        let hi = _mm_sub_pd(c, _mm_sub_pd(c, a));
+        (hi, _mm_sub_pd(a, hi))
+    }
+
+    // error-free transformation a * b = p + e without FMA (Dekker)
+    #[inline(always)]
+    unsafe fn two_prod(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+        let p = _mm_mul_pd(a, b);
+        let (a_hi, a_lo) = split(a);
+        let (b_hi, b_lo) = split(b);
+        let e = _mm_sub_pd(_mm_mul_pd(a_hi, b_hi), p);
+        let e = _mm_add_pd(e, _mm_mul_pd(a_hi, b_lo));
+        let e = _mm_add_pd(e, _mm_mul_pd(a_lo, b_hi));
+        let e = _mm_add_pd(e, _mm_mul_pd(a_lo, b_lo));
+        (p, e)
+    }
+
+    // lanes where two_prod(a, b) = p + e is not exact: the split overflows above 2^996, and the
+    // partial products underflow when p is tiny but a and b are not zero
+    #[inline(always)]
+    unsafe fn two_prod_inexact(a: __m128d, b: __m128d, p: __m128d) -> __m128d {
+        let abs = |v| _mm_andnot_pd(_mm_set1_pd(-0.0), v);
+        let large = _mm_set1_pd(f64::powi(2.0, 995));
+        let small = _mm_set1_pd(f64::powi(2.0, -900));
+        let overflow = _mm_or_pd(
+            _mm_or_pd(_mm_cmpgt_pd(abs(a), large), _mm_cmpgt_pd(abs(b), large)),
+            _mm_cmpgt_pd(abs(p), large),
+        );
+        let nonzero = _mm_and_pd(
+            _mm_cmpneq_pd(a, _mm_setzero_pd()),
+            _mm_cmpneq_pd(b, _mm_setzero_pd()),
+        );
+        let underflow = _mm_and_pd(_mm_cmplt_pd(abs(p), small), nonzero);
+        _mm_or_pd(overflow, underflow)
+    }
+
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = _mm_loadu_pd(x.as_ptr().add(i));
+        let mut s = _mm_set1_pd(c[0]);
+        let mut e = _mm_setzero_pd();
+        let mut inexact = _mm_setzero_pd();
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let c_jx2 = _mm_set1_pd(c[j]);
+            let neg_base_jx2 = _mm_set1_pd(-base[j - 1]);
+            let (t, dt) = two_sum(x_ix2, neg_base_jx2);
+            let (p, pi) = two_prod(t, s);
+            inexact = _mm_or_pd(inexact, two_prod_inexact(t, s, p));
+            let (s_new, sigma) = two_sum(p, c_jx2);
+            let err = _mm_add_pd(_mm_add_pd(pi, sigma), _mm_mul_pd(s, dt));
+            e = _mm_add_pd(_mm_mul_pd(e, t), err);
+            s = s_new;
+        }
+
+        if _mm_movemask_pd(inexact) != 0 {
+            // the FMA based scalar two_prod is exact in the whole range
+            crate::compensated::comp_eval(&x[i..i + 2], c, base, &mut result[i..i + 2]);
+        } else {
+            let ptr = result.as_mut_ptr().add(i);
+            _mm_storeu_pd(ptr, _mm_add_pd(s, e));
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,fma")]
+#[inline]
+pub(crate) unsafe fn avx2_comp_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    // error-free transformation a + b = s + e
+    #[inline(always)]
+    unsafe fn two_sum(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+        let s = _mm256_add_pd(a, b);
+        let bb = _mm256_sub_pd(s, a);
+        let e = _mm256_add_pd(_mm256_sub_pd(a, _mm256_sub_pd(s, bb)), _mm256_sub_pd(b, bb));
+        (s, e)
+    }
+
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let mut s = _mm256_set1_pd(c[0]);
+        let mut e = _mm256_setzero_pd();
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let c_jx4 = _mm256_set1_pd(c[j]);
+            let neg_base_jx4 = _mm256_set1_pd(-base[j - 1]);
+            let (t, dt) = two_sum(x_ix4, neg_base_jx4);
+            // error-free transformation t * s = p + pi
+            let p = _mm256_mul_pd(t, s);
+            let pi = _mm256_fmsub_pd(t, s, p);
+            let (s_new, sigma) = two_sum(p, c_jx4);
+            let err = _mm256_add_pd(_mm256_add_pd(pi, sigma), _mm256_mul_pd(s, dt));
+            e = _mm256_add_pd(_mm256_mul_pd(e, t), err);
+            s = s_new;
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm256_storeu_pd(ptr, _mm256_add_pd(s, e));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_comp_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    // error-free transformation a + b = s + e
+    #[inline(always)]
+    unsafe fn two_sum(a: __m512d, b: __m512d) -> (__m512d, __m512d) {
+        let s = _mm512_add_pd(a, b);
+        let bb = _mm512_sub_pd(s, a);
+        let e = _mm512_add_pd(_mm512_sub_pd(a, _mm512_sub_pd(s, bb)), _mm512_sub_pd(b, bb));
+        (s, e)
+    }
+
+    for i in (0..x.len()).step_by(8) {
+        // load 8 x to reg
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let mut s = _mm512_set1_pd(c[0]);
+        let mut e = _mm512_setzero_pd();
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let c_jx8 = _mm512_set1_pd(c[j]);
+            let neg_base_jx8 = _mm512_set1_pd(-base[j - 1]);
+            let (t, dt) = two_sum(x_ix8, neg_base_jx8);
+            // error-free transformation t * s = p + pi
+            let p = _mm512_mul_pd(t, s);
+            let pi = _mm512_fmsub_pd(t, s, p);
+            let (s_new, sigma) = two_sum(p, c_jx8);
+            let err = _mm512_add_pd(_mm512_add_pd(pi, sigma), _mm512_mul_pd(s, dt));
+            e = _mm512_add_pd(_mm512_mul_pd(e, t), err);
+            s = s_new;
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm512_storeu_pd(ptr, _mm512_add_pd(s, e));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_derivatives_eval(
+    x: &[f64],
+    c: &[f64],
+    base: &[f64],
+    result: &mut [Vec<f64>],
+) {
+    use std::arch::x86_64::*;
+
+    let k = result.len() - 1;
+    let factorials = crate::derivatives::factorials(k);
+    let mut d = vec![_mm_setzero_pd(); k + 1];
+    for i in (0..x.len()).step_by(2) {
+        // load 2 x to reg
+        let x_ix2 = _mm_loadu_pd(x.as_ptr().add(i));
+        d.fill(_mm_setzero_pd());
+        d[0] = _mm_set1_pd(c[0]);
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let t = _mm_sub_pd(x_ix2, _mm_set1_pd(base[j - 1]));
+            for m in (1..=k).rev() {
+                d[m] = _mm_add_pd(_mm_mul_pd(d[m], t), d[m - 1]);
+            }
+            d[0] = _mm_add_pd(_mm_mul_pd(d[0], t), _mm_set1_pd(c[j]));
+        }
+
+        for (m, row) in result.iter_mut().enumerate() {
+            let y = _mm_mul_pd(d[m], _mm_set1_pd(factorials[m]));
+            _mm_storeu_pd(row.as_mut_ptr().add(i), y);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_derivatives_eval(
+    x: &[f64],
+    c: &[f64],
+    base: &[f64],
+    result: &mut [Vec<f64>],
+) {
+    use std::arch::x86_64::*;
+
+    let k = result.len() - 1;
+    let factorials = crate::derivatives::factorials(k);
+    let mut d = vec![_mm256_setzero_pd(); k + 1];
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        d.fill(_mm256_setzero_pd());
+        d[0] = _mm256_set1_pd(c[0]);
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let t = _mm256_sub_pd(x_ix4, _mm256_set1_pd(base[j - 1]));
+            for m in (1..=k).rev() {
+                d[m] = _mm256_add_pd(_mm256_mul_pd(d[m], t), d[m - 1]);
+            }
+            d[0] = _mm256_add_pd(_mm256_mul_pd(d[0], t), _mm256_set1_pd(c[j]));
+        }
+
+        for (m, row) in result.iter_mut().enumerate() {
+            let y = _mm256_mul_pd(d[m], _mm256_set1_pd(factorials[m]));
+            _mm256_storeu_pd(row.as_mut_ptr().add(i), y);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_derivatives_eval(
+    x: &[f64],
+    c: &[f64],
+    base: &[f64],
+    result: &mut [Vec<f64>],
+) {
+    use std::arch::x86_64::*;
+
+    let k = result.len() - 1;
+    let factorials = crate::derivatives::factorials(k);
+    let mut d = vec![_mm512_setzero_pd(); k + 1];
+    for i in (0..x.len()).step_by(8) {
+        // load 8 x to reg
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        d.fill(_mm512_setzero_pd());
+        d[0] = _mm512_set1_pd(c[0]);
+
+        for j in 1..c.len() {
+            // notice that c and b are reversed
+            let t = _mm512_sub_pd(x_ix8, _mm512_set1_pd(base[j - 1]));
+            for m in (1..=k).rev() {
+                d[m] = _mm512_add_pd(_mm512_mul_pd(d[m], t), d[m - 1]);
+            }
+            d[0] = _mm512_add_pd(_mm512_mul_pd(d[0], t), _mm512_set1_pd(c[j]));
+        }
+
+        for (m, row) in result.iter_mut().enumerate() {
+            let y = _mm512_mul_pd(d[m], _mm512_set1_pd(factorials[m]));
+            _mm512_storeu_pd(row.as_mut_ptr().add(i), y);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse")]
+#[inline]
+pub(crate) unsafe fn sse_eval_f32(x: &[f32], c: &[f32], base: &[f32], result: &mut [f32]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        // load 4 x to reg
+        let x_ix4 = _mm_loadu_ps(x.as_ptr().add(i));
+        let mut y_ix4 = _mm_set1_ps(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j, notice that c and b are reversed
+            let sub_0 = _mm_sub_ps(x_ix4, _mm_set1_ps(base[j - 1]));
+            let mul_0 = _mm_mul_ps(sub_0, y_ix4);
+            y_ix4 = _mm_add_ps(mul_0, _mm_set1_ps(c[j]));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm_storeu_ps(ptr, y_ix4);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_eval_f32(x: &[f32], c: &[f32], base: &[f32], result: &mut [f32]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        // load 8 x to reg
+        let x_ix8 = _mm256_loadu_ps(x.as_ptr().add(i));
+        let mut y_ix8 = _mm256_set1_ps(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j, notice that c and b are reversed
+            let sub_0 = _mm256_sub_ps(x_ix8, _mm256_set1_ps(base[j - 1]));
+            let mul_0 = _mm256_mul_ps(sub_0, y_ix8);
+            y_ix8 = _mm256_add_ps(mul_0, _mm256_set1_ps(c[j]));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm256_storeu_ps(ptr, y_ix8);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_eval_f32(x: &[f32], c: &[f32], base: &[f32], result: &mut [f32]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(16) {
+        // load 16 x to reg
+        let x_ix16 = _mm512_loadu_ps(x.as_ptr().add(i));
+        let mut y_ix16 = _mm512_set1_ps(c[0]);
+
+        for j in 1..c.len() {
+            // calc (x-b_j)y+c_j, notice that c and b are reversed
+            let sub_0 = _mm512_sub_ps(x_ix16, _mm512_set1_ps(base[j - 1]));
+            let mul_0 = _mm512_mul_ps(sub_0, y_ix16);
+            y_ix16 = _mm512_add_ps(mul_0, _mm512_set1_ps(c[j]));
+        }
+
+        let ptr = result.as_mut_ptr().add(i);
+        _mm512_storeu_ps(ptr, y_ix16);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_complex_eval(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    result: (&mut [f64], &mut [f64]),
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..z.0.len()).step_by(2) {
+        // load 2 z to reg, real and imaginary parts are split
+        let z_re = _mm_loadu_pd(z.0.as_ptr().add(i));
+        let z_im = _mm_loadu_pd(z.1.as_ptr().add(i));
+        let mut s_re = _mm_set1_pd(c.0[0]);
+        let mut s_im = _mm_set1_pd(c.1[0]);
+
+        for j in 1..c.0.len() {
+            // calc (z-b_j)s+c_j, notice that c and b are reversed
+            let t_re = _mm_sub_pd(z_re, _mm_set1_pd(base.0[j - 1]));
+            let t_im = _mm_sub_pd(z_im, _mm_set1_pd(base.1[j - 1]));
+            let p_re = _mm_sub_pd(_mm_mul_pd(t_re, s_re), _mm_mul_pd(t_im, s_im));
+            let p_im = _mm_add_pd(_mm_mul_pd(t_re, s_im), _mm_mul_pd(t_im, s_re));
+            s_re = _mm_add_pd(p_re, _mm_set1_pd(c.0[j]));
+            s_im = _mm_add_pd(p_im, _mm_set1_pd(c.1[j]));
+        }
+
+        _mm_storeu_pd(result.0.as_mut_ptr().add(i), s_re);
+        _mm_storeu_pd(result.1.as_mut_ptr().add(i), s_im);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_complex_eval(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    result: (&mut [f64], &mut [f64]),
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..z.0.len()).step_by(4) {
+        // load 4 z to reg, real and imaginary parts are split
+        let z_re = _mm256_loadu_pd(z.0.as_ptr().add(i));
+        let z_im = _mm256_loadu_pd(z.1.as_ptr().add(i));
+        let mut s_re = _mm256_set1_pd(c.0[0]);
+        let mut s_im = _mm256_set1_pd(c.1[0]);
+
+        for j in 1..c.0.len() {
+            // calc (z-b_j)s+c_j, notice that c and b are reversed
+            let t_re = _mm256_sub_pd(z_re, _mm256_set1_pd(base.0[j - 1]));
+            let t_im = _mm256_sub_pd(z_im, _mm256_set1_pd(base.1[j - 1]));
+            let p_re = _mm256_sub_pd(_mm256_mul_pd(t_re, s_re), _mm256_mul_pd(t_im, s_im));
+            let p_im = _mm256_add_pd(_mm256_mul_pd(t_re, s_im), _mm256_mul_pd(t_im, s_re));
+            s_re = _mm256_add_pd(p_re, _mm256_set1_pd(c.0[j]));
+            s_im = _mm256_add_pd(p_im, _mm256_set1_pd(c.1[j]));
+        }
+
+        _mm256_storeu_pd(result.0.as_mut_ptr().add(i), s_re);
+        _mm256_storeu_pd(result.1.as_mut_ptr().add(i), s_im);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_complex_eval(
+    z: (&[f64], &[f64]),
+    c: (&[f64], &[f64]),
+    base: (&[f64], &[f64]),
+    result: (&mut [f64], &mut [f64]),
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..z.0.len()).step_by(8) {
+        // load 8 z to reg, real and imaginary parts are split
+        let z_re = _mm512_loadu_pd(z.0.as_ptr().add(i));
+        let z_im = _mm512_loadu_pd(z.1.as_ptr().add(i));
+        let mut s_re = _mm512_set1_pd(c.0[0]);
+        let mut s_im = _mm512_set1_pd(c.1[0]);
+
+        for j in 1..c.0.len() {
+            // calc (z-b_j)s+c_j, notice that c and b are reversed
+            let t_re = _mm512_sub_pd(z_re, _mm512_set1_pd(base.0[j - 1]));
+            let t_im = _mm512_sub_pd(z_im, _mm512_set1_pd(base.1[j - 1]));
+            let p_re = _mm512_sub_pd(_mm512_mul_pd(t_re, s_re), _mm512_mul_pd(t_im, s_im));
+            let p_im = _mm512_add_pd(_mm512_mul_pd(t_re, s_im), _mm512_mul_pd(t_im, s_re));
+            s_re = _mm512_add_pd(p_re, _mm512_set1_pd(c.0[j]));
+            s_im = _mm512_add_pd(p_im, _mm512_set1_pd(c.1[j]));
+        }
+
+        _mm512_storeu_pd(result.0.as_mut_ptr().add(i), s_re);
+        _mm512_storeu_pd(result.1.as_mut_ptr().add(i), s_im);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_batch_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    let n = c.len() / 8;
+    for (k, x_k) in x.iter().enumerate() {
+        // the lanes are 8 polynomials, x is shared
+        let x_kx2 = _mm_set1_pd(*x_k);
+        for l in (0..8).step_by(2) {
+            let mut y_lx2 = _mm_setzero_pd();
+            for j in 0..n {
+                let c_jx2 = _mm_loadu_pd(c.as_ptr().add(j * 8 + l));
+                let base_jx2 = _mm_loadu_pd(base.as_ptr().add(j * 8 + l));
+                // calc (x-b_j)(c_j+y), the last c is only added
+                let add_0 = _mm_add_pd(c_jx2, y_lx2);
+                if j + 1 < n {
+                    y_lx2 = _mm_mul_pd(_mm_sub_pd(x_kx2, base_jx2), add_0);
+                } else {
+                    y_lx2 = add_0;
+                }
+            }
+            _mm_storeu_pd(result.as_mut_ptr().add(k * 8 + l), y_lx2);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_batch_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    let n = c.len() / 8;
+    for (k, x_k) in x.iter().enumerate() {
+        // the lanes are 8 polynomials, x is shared
+        let x_kx4 = _mm256_set1_pd(*x_k);
+        let mut y_0 = _mm256_setzero_pd();
+        let mut y_1 = _mm256_setzero_pd();
+        for j in 0..n {
+            let c_0 = _mm256_loadu_pd(c.as_ptr().add(j * 8));
+            let c_1 = _mm256_loadu_pd(c.as_ptr().add(j * 8 + 4));
+            let base_0 = _mm256_loadu_pd(base.as_ptr().add(j * 8));
+            let base_1 = _mm256_loadu_pd(base.as_ptr().add(j * 8 + 4));
+            // calc (x-b_j)(c_j+y), the last c is only added
+            let add_0 = _mm256_add_pd(c_0, y_0);
+            let add_1 = _mm256_add_pd(c_1, y_1);
+            if j + 1 < n {
+                y_0 = _mm256_mul_pd(_mm256_sub_pd(x_kx4, base_0), add_0);
+                y_1 = _mm256_mul_pd(_mm256_sub_pd(x_kx4, base_1), add_1);
+            } else {
+                y_0 = add_0;
+                y_1 = add_1;
+            }
+        }
+        _mm256_storeu_pd(result.as_mut_ptr().add(k * 8), y_0);
+        _mm256_storeu_pd(result.as_mut_ptr().add(k * 8 + 4), y_1);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_batch_eval(x: &[f64], c: &[f64], base: &[f64], result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    let n = c.len() / 8;
+    for (k, x_k) in x.iter().enumerate() {
+        // the lanes are 8 polynomials, x is shared
+        let x_kx8 = _mm512_set1_pd(*x_k);
+        let mut y_x8 = _mm512_setzero_pd();
+        for j in 0..n {
+            let c_jx8 = _mm512_loadu_pd(c.as_ptr().add(j * 8));
+            let base_jx8 = _mm512_loadu_pd(base.as_ptr().add(j * 8));
+            // calc (x-b_j)(c_j+y), the last c is only added
+            let add_0 = _mm512_add_pd(c_jx8, y_x8);
+            if j + 1 < n {
+                y_x8 = _mm512_mul_pd(_mm512_sub_pd(x_kx8, base_jx8), add_0);
+            } else {
+                y_x8 = add_0;
+            }
+        }
+        _mm512_storeu_pd(result.as_mut_ptr().add(k * 8), y_x8);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_clenshaw_eval(
+    x: &[f64],
+    c: &[f64],
+    mid: f64,
+    scale: f64,
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        // map 2 x to t in [-1, 1]
+        let x_ix2 = _mm_loadu_pd(x.as_ptr().add(i));
+        let t = _mm_mul_pd(_mm_sub_pd(x_ix2, _mm_set1_pd(mid)), _mm_set1_pd(scale));
+        let two_t = _mm_add_pd(t, t);
+        let mut b_1 = _mm_setzero_pd();
+        let mut b_2 = _mm_setzero_pd();
+
+        for k in (1..c.len()).rev() {
+            // b_k = 2t b_{k+1} - b_{k+2} + c_k
+            let b_k = _mm_sub_pd(_mm_mul_pd(two_t, b_1), b_2);
+            b_2 = b_1;
+            b_1 = _mm_add_pd(b_k, _mm_set1_pd(c[k]));
+        }
+
+        let y = _mm_add_pd(_mm_sub_pd(_mm_mul_pd(t, b_1), b_2), _mm_set1_pd(c[0]));
+        _mm_storeu_pd(result.as_mut_ptr().add(i), y);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_clenshaw_eval(
+    x: &[f64],
+    c: &[f64],
+    mid: f64,
+    scale: f64,
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        // map 4 x to t in [-1, 1]
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let t = _mm256_mul_pd(
+            _mm256_sub_pd(x_ix4, _mm256_set1_pd(mid)),
+            _mm256_set1_pd(scale),
+        );
+        let two_t = _mm256_add_pd(t, t);
+        let mut b_1 = _mm256_setzero_pd();
+        let mut b_2 = _mm256_setzero_pd();
+
+        for k in (1..c.len()).rev() {
+            // b_k = 2t b_{k+1} - b_{k+2} + c_k
+            let b_k = _mm256_sub_pd(_mm256_mul_pd(two_t, b_1), b_2);
+            b_2 = b_1;
+            b_1 = _mm256_add_pd(b_k, _mm256_set1_pd(c[k]));
+        }
+
+        let y = _mm256_add_pd(
+            _mm256_sub_pd(_mm256_mul_pd(t, b_1), b_2),
+            _mm256_set1_pd(c[0]),
+        );
+        _mm256_storeu_pd(result.as_mut_ptr().add(i), y);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_clenshaw_eval(
+    x: &[f64],
+    c: &[f64],
+    mid: f64,
+    scale: f64,
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        // map 8 x to t in [-1, 1]
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let t = _mm512_mul_pd(
+            _mm512_sub_pd(x_ix8, _mm512_set1_pd(mid)),
+            _mm512_set1_pd(scale),
+        );
+        let two_t = _mm512_add_pd(t, t);
+        let mut b_1 = _mm512_setzero_pd();
+        let mut b_2 = _mm512_setzero_pd();
+
+        for k in (1..c.len()).rev() {
+            // b_k = 2t b_{k+1} - b_{k+2} + c_k
+            let b_k = _mm512_sub_pd(_mm512_mul_pd(two_t, b_1), b_2);
+            b_2 = b_1;
+            b_1 = _mm512_add_pd(b_k, _mm512_set1_pd(c[k]));
+        }
+
+        let y = _mm512_add_pd(
+            _mm512_sub_pd(_mm512_mul_pd(t, b_1), b_2),
+            _mm512_set1_pd(c[0]),
+        );
+        _mm512_storeu_pd(result.as_mut_ptr().add(i), y);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_recurrence_eval(
+    x: &[f64],
+    c: &[f64],
+    (a, b, g): (&[f64], &[f64], &[f64]),
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        let x_ix2 = _mm_loadu_pd(x.as_ptr().add(i));
+        let mut s_1 = _mm_setzero_pd();
+        let mut s_2 = _mm_setzero_pd();
+
+        for k in (0..c.len()).rev() {
+            // s_k = (a_k x + b_k) s_{k+1} - g_k s_{k+2} + c_k
+            let p = _mm_add_pd(_mm_mul_pd(_mm_set1_pd(a[k]), x_ix2), _mm_set1_pd(b[k]));
+            let s_k = _mm_sub_pd(_mm_mul_pd(p, s_1), _mm_mul_pd(_mm_set1_pd(g[k]), s_2));
+            s_2 = s_1;
+            s_1 = _mm_add_pd(s_k, _mm_set1_pd(c[k]));
+        }
+
+        _mm_storeu_pd(result.as_mut_ptr().add(i), s_1);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_recurrence_eval(
+    x: &[f64],
+    c: &[f64],
+    (a, b, g): (&[f64], &[f64], &[f64]),
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let mut s_1 = _mm256_setzero_pd();
+        let mut s_2 = _mm256_setzero_pd();
+
+        for k in (0..c.len()).rev() {
+            // s_k = (a_k x + b_k) s_{k+1} - g_k s_{k+2} + c_k
+            let p = _mm256_add_pd(
+                _mm256_mul_pd(_mm256_set1_pd(a[k]), x_ix4),
+                _mm256_set1_pd(b[k]),
+            );
+            let s_k = _mm256_sub_pd(
+                _mm256_mul_pd(p, s_1),
+                _mm256_mul_pd(_mm256_set1_pd(g[k]), s_2),
+            );
+            s_2 = s_1;
+            s_1 = _mm256_add_pd(s_k, _mm256_set1_pd(c[k]));
+        }
+
+        _mm256_storeu_pd(result.as_mut_ptr().add(i), s_1);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_recurrence_eval(
+    x: &[f64],
+    c: &[f64],
+    (a, b, g): (&[f64], &[f64], &[f64]),
+    result: &mut [f64],
+) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let mut s_1 = _mm512_setzero_pd();
+        let mut s_2 = _mm512_setzero_pd();
+
+        for k in (0..c.len()).rev() {
+            // s_k = (a_k x + b_k) s_{k+1} - g_k s_{k+2} + c_k
+            let p = _mm512_add_pd(
+                _mm512_mul_pd(_mm512_set1_pd(a[k]), x_ix8),
+                _mm512_set1_pd(b[k]),
+            );
+            let s_k = _mm512_sub_pd(
+                _mm512_mul_pd(p, s_1),
+                _mm512_mul_pd(_mm512_set1_pd(g[k]), s_2),
+            );
+            s_2 = s_1;
+            s_1 = _mm512_add_pd(s_k, _mm512_set1_pd(c[k]));
+        }
+
+        _mm512_storeu_pd(result.as_mut_ptr().add(i), s_1);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+#[inline]
+pub(crate) unsafe fn sse2_rational_eval(x: &[f64], (p, q): (&[f64], &[f64]), result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(2) {
+        let x_ix2 = _mm_loadu_pd(x.as_ptr().add(i));
+        let mut num = _mm_set1_pd(p[0]);
+        let mut den = _mm_set1_pd(q[0]);
+
+        for j in 1..p.len() {
+            // numerator and denominator share the loads of x
+            num = _mm_add_pd(_mm_mul_pd(num, x_ix2), _mm_set1_pd(p[j]));
+            den = _mm_add_pd(_mm_mul_pd(den, x_ix2), _mm_set1_pd(q[j]));
+        }
+
+        _mm_storeu_pd(result.as_mut_ptr().add(i), _mm_div_pd(num, den));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[inline]
+pub(crate) unsafe fn avx2_rational_eval(x: &[f64], (p, q): (&[f64], &[f64]), result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(4) {
+        let x_ix4 = _mm256_loadu_pd(x.as_ptr().add(i));
+        let mut num = _mm256_set1_pd(p[0]);
+        let mut den = _mm256_set1_pd(q[0]);
+
+        for j in 1..p.len() {
+            // numerator and denominator share the loads of x
+            num = _mm256_add_pd(_mm256_mul_pd(num, x_ix4), _mm256_set1_pd(p[j]));
+            den = _mm256_add_pd(_mm256_mul_pd(den, x_ix4), _mm256_set1_pd(q[j]));
+        }
+
+        _mm256_storeu_pd(result.as_mut_ptr().add(i), _mm256_div_pd(num, den));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+#[inline]
+pub(crate) unsafe fn avx512_rational_eval(x: &[f64], (p, q): (&[f64], &[f64]), result: &mut [f64]) {
+    use std::arch::x86_64::*;
+
+    for i in (0..x.len()).step_by(8) {
+        let x_ix8 = _mm512_loadu_pd(x.as_ptr().add(i));
+        let mut num = _mm512_set1_pd(p[0]);
+        let mut den = _mm512_set1_pd(q[0]);
+
+        for j in 1..p.len() {
+            // numerator and denominator share the loads of x
+            num = _mm512_add_pd(_mm512_mul_pd(num, x_ix8), _mm512_set1_pd(p[j]));
+            den = _mm512_add_pd(_mm512_mul_pd(den, x_ix8), _mm512_set1_pd(q[j]));
+        }
+
+        _mm512_storeu_pd(result.as_mut_ptr().add(i), _mm512_div_pd(num, den));
+    }
+}